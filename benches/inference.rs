@@ -1,7 +1,7 @@
 //! Benchmarks for fastdateinfer
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
-use fastdateinfer::{infer, infer_with_options, InferOptions};
+use fastdateinfer::{infer, infer_with_context, infer_with_options, InferContext, InferOptions};
 
 fn generate_dates_dmy(n: usize) -> Vec<String> {
     (0..n)
@@ -9,6 +9,25 @@ fn generate_dates_dmy(n: usize) -> Vec<String> {
         .collect()
 }
 
+fn generate_dates_month_name(n: usize) -> Vec<String> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    (0..n)
+        .map(|i| format!("{:02} {} 2025", (i % 28) + 1, MONTHS[i % 12]))
+        .collect()
+}
+
+fn generate_dates_month_name_full(n: usize) -> Vec<String> {
+    const MONTHS: [&str; 12] = [
+        "January", "February", "March", "April", "May", "June",
+        "July", "August", "September", "October", "November", "December",
+    ];
+    (0..n)
+        .map(|i| format!("{:02} {} 2025", (i % 28) + 1, MONTHS[i % 12]))
+        .collect()
+}
+
 /// Generate ambiguous dates with a single disambiguating date at a non-sampled index.
 fn generate_dates_prescan(n: usize, disambig_value: &str, disambig_index: usize) -> Vec<String> {
     let mut dates: Vec<String> = (0..n)
@@ -34,6 +53,28 @@ fn bench_inference(c: &mut Criterion) {
         );
     }
 
+    for size in [1000, 100000] {
+        let dates = generate_dates_month_name(size);
+        group.bench_with_input(
+            BenchmarkId::new("month_name", size),
+            &dates,
+            |b, dates| {
+                b.iter(|| infer(black_box(dates)))
+            },
+        );
+    }
+
+    for size in [1000, 100000] {
+        let dates = generate_dates_month_name_full(size);
+        group.bench_with_input(
+            BenchmarkId::new("month_name_full", size),
+            &dates,
+            |b, dates| {
+                b.iter(|| infer(black_box(dates)))
+            },
+        );
+    }
+
     group.finish();
 }
 
@@ -124,5 +165,38 @@ fn bench_strict(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_inference, bench_tokenization, bench_prescan, bench_strict);
+fn bench_context(c: &mut Criterion) {
+    let mut group = c.benchmark_group("small_columns");
+
+    // 1000 columns of 5 dates each, the shape of a wide table
+    let columns: Vec<Vec<String>> = (0..1000)
+        .map(|i| {
+            (0..5)
+                .map(|j| format!("{:02}/{:02}/2025", (i + j) % 28 + 1, j % 12 + 1))
+                .collect()
+        })
+        .collect();
+    let options = InferOptions::default();
+
+    group.bench_function("fresh", |b| {
+        b.iter(|| {
+            for column in &columns {
+                let _ = infer_with_options(black_box(column), &options);
+            }
+        })
+    });
+
+    group.bench_function("with_context", |b| {
+        let mut ctx = InferContext::new();
+        b.iter(|| {
+            for column in &columns {
+                let _ = infer_with_context(&mut ctx, black_box(column), &options);
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_inference, bench_tokenization, bench_prescan, bench_strict, bench_context);
 criterion_main!(benches);
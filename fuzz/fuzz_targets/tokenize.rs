@@ -0,0 +1,23 @@
+//! Fuzz target for the tokenizer and the full inference pipeline.
+//!
+//! Run with `cargo +nightly fuzz run tokenize -- -max_len=4096`.
+//! Arbitrary bytes are lossy-converted to UTF-8, so invalid sequences become
+//! U+FFFD and hit the tokenizer's unknown-character branch.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+
+    // Single string: exercises the +/- offset branch and unknown-char skip
+    if let Ok(tokens) = fastdateinfer::tokenize(&input) {
+        assert!(!tokens.is_empty());
+    }
+
+    // One date per line: exercises consensus, rules and format generation
+    let rows: Vec<&str> = input.lines().collect();
+    let _ = fastdateinfer::infer(&rows);
+    let _ = fastdateinfer::infer_best_effort(&rows);
+});
@@ -1,7 +1,13 @@
 """Fast, consensus-based date format inference."""
 
 from .fastdateinfer import (
+    AmbiguousDateError,
+    EmptyInputError,
+    InconsistentFormatsError,
+    InferenceError,
     InferResult,
+    LowConfidenceError,
+    StrictValidationError,
     infer,
     infer_format,
     infer_batch,
@@ -9,7 +15,13 @@ from .fastdateinfer import (
 )
 
 __all__ = [
+    "AmbiguousDateError",
+    "EmptyInputError",
+    "InconsistentFormatsError",
+    "InferenceError",
     "InferResult",
+    "LowConfidenceError",
+    "StrictValidationError",
     "infer",
     "infer_format",
     "infer_batch",
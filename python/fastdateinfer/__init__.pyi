@@ -1,9 +1,27 @@
 """Type stubs for fastdateinfer."""
 
-from typing import Dict, List
+from typing import Dict, List, Union
 
 __version__: str
 
+class InferenceError(ValueError):
+    """Base class for inference failures."""
+
+class EmptyInputError(InferenceError):
+    """No dates to infer from (empty list, or nothing that tokenizes)."""
+
+class InconsistentFormatsError(InferenceError):
+    """No token structure is shared by a majority of the dates."""
+
+class LowConfidenceError(InferenceError):
+    """Confidence fell below ``min_confidence``."""
+
+class StrictValidationError(InferenceError):
+    """Some dates don't match the inferred format with ``strict=True``."""
+
+class AmbiguousDateError(InferenceError):
+    """Day and month could not be told apart."""
+
 class InferResult:
     """Result of date format inference."""
 
@@ -14,13 +32,36 @@ class InferResult:
     """Confidence score (0.0 - 1.0)."""
 
     token_types: List[str]
-    """Resolved token types as strings."""
+    """Resolved token types as stable tags, one per token position.
+
+    Tags: ``year4``, ``year2``, ``month``, ``day``, ``month_name``,
+    ``month_name_short``, ``day_of_year``, ``weekday_name``, ``weekday_short``,
+    ``weekday_number``, ``weekday_number_sunday``, ``iso_year``, ``iso_year2``, ``iso_week``, ``hour24``, ``hour12``, ``minute``, ``second``, ``subsecond``, ``ampm``,
+    ``time_word``, ``tz_offset``, ``tz_name``, ``tz_z``, ``day_or_month``, ``unknown``,
+    and ``sep:<char>`` for separators (e.g. ``sep:/``).
+    """
+
+    skipped_count: int
+    """Number of inputs skipped as nulls (only non-zero with ``skip_nulls=True``)."""
+
+    used_preference: bool
+    """True when no date proved the day/month order and ``prefer_dayfirst`` chose it."""
+
+    components: List[Dict[str, Union[str, int]]]
+    """One dict per token position: ``type`` (the tag, as in ``token_types``),
+    ``specifier`` (its strptime specifier, or the literal separator) and
+    ``position`` (the token index)."""
+
+    def to_regex(self) -> str:
+        """An anchored regex matching the inferred structure, one capture group per specifier."""
+        ...
 
 def infer(
     dates: List[str],
     prefer_dayfirst: bool = True,
     min_confidence: float = 0.0,
     strict: bool = False,
+    skip_nulls: bool = False,
 ) -> InferResult:
     """
     Infer date format from a list of example date strings.
@@ -33,12 +74,14 @@ def infer(
         prefer_dayfirst: Prefer DD/MM format for ambiguous dates (default: True)
         min_confidence: Minimum confidence threshold (default: 0.0)
         strict: Fail if any example doesn't match (default: False)
+        skip_nulls: Skip null markers like "", "N/A", "null" instead of
+            counting them as unparseable (default: False)
 
     Returns:
         InferResult with format string and confidence score
 
     Raises:
-        ValueError: If inference fails
+        InferenceError: If inference fails; see its subclasses
 
     Example:
         >>> result = infer(["15/03/2025", "01/02/2025"])
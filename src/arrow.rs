@@ -0,0 +1,73 @@
+//! Inference over Arrow string arrays (`arrow` feature)
+//!
+//! Values are borrowed straight from the array's buffers, so no `String` is
+//! allocated per row. Null slots are skipped and counted in `skipped_count`.
+
+use arrow_array::{Array, GenericStringArray, OffsetSizeTrait};
+
+use crate::{infer_with_options, InferOptions, InferResult, Result};
+
+/// Infer date format from an Arrow `StringArray` or `LargeStringArray`.
+///
+/// # Example
+///
+/// ```
+/// use arrow_array::StringArray;
+/// use fastdateinfer::infer_utf8;
+///
+/// let array = StringArray::from(vec![Some("15/03/2025"), None, Some("01/02/2025")]);
+/// let result = infer_utf8(&array).unwrap();
+/// assert_eq!(result.format, "%d/%m/%Y");
+/// assert_eq!(result.skipped_count, 1);
+/// ```
+pub fn infer_utf8<O: OffsetSizeTrait>(array: &GenericStringArray<O>) -> Result<InferResult> {
+    infer_utf8_with_options(array, &InferOptions::default())
+}
+
+/// Infer date format from an Arrow string array with custom options.
+pub fn infer_utf8_with_options<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    options: &InferOptions,
+) -> Result<InferResult> {
+    let values: Vec<&str> = array.iter().flatten().collect();
+    let mut result = infer_with_options(&values, options)?;
+    result.skipped_count += array.null_count();
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DateInferError;
+    use arrow_array::{LargeStringArray, StringArray};
+
+    #[test]
+    fn test_infer_string_array() {
+        let array = StringArray::from(vec!["2025-01-15", "2025-03-20", "2025-12-01"]);
+        let result = infer_utf8(&array).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d");
+        assert_eq!(result.skipped_count, 0);
+    }
+
+    #[test]
+    fn test_infer_skips_nulls() {
+        let array = StringArray::from(vec![Some("15/03/2025"), None, None, Some("01/02/2025")]);
+        let result = infer_utf8(&array).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+        assert_eq!(result.skipped_count, 2);
+    }
+
+    #[test]
+    fn test_infer_large_string_array() {
+        let array = LargeStringArray::from(vec!["15 Jan 2025", "20 Mar 2025"]);
+        let result = infer_utf8(&array).unwrap();
+        assert_eq!(result.format, "%d %b %Y");
+    }
+
+    #[test]
+    fn test_infer_all_null() {
+        let array = StringArray::from(vec![None::<&str>, None]);
+        assert!(matches!(infer_utf8(&array), Err(DateInferError::EmptyInput)));
+    }
+}
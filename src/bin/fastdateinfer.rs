@@ -0,0 +1,117 @@
+//! Command-line date format inference (`cli` feature)
+//!
+//! Reads one date per line from a file or stdin and prints the inferred
+//! strptime format and confidence.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::process::ExitCode;
+
+use fastdateinfer::{analyze_with_options, infer_from_reader, infer_with_options, InferOptions, InferResult};
+
+const USAGE: &str = "\
+Usage: fastdateinfer [OPTIONS] [FILE]
+
+Infer the strptime format of dates read one per line from FILE or stdin.
+
+Options:
+      --monthfirst            Prefer MM/DD for fully ambiguous dates
+      --strict                Fail if any date doesn't match the format
+      --min-confidence <N>    Fail below this confidence (0.0 - 1.0)
+      --skip-nulls            Skip null markers like N/A and NaN
+      --explain               Print the candidate types at each position
+  -h, --help                  Print this help";
+
+struct Args {
+    options: InferOptions,
+    explain: bool,
+    path: Option<String>,
+}
+
+fn parse_args() -> Result<Option<Args>, lexopt::Error> {
+    use lexopt::prelude::*;
+
+    let mut args = Args {
+        options: InferOptions::default(),
+        explain: false,
+        path: None,
+    };
+    let mut parser = lexopt::Parser::from_env();
+    while let Some(arg) = parser.next()? {
+        match arg {
+            Long("monthfirst") => args.options.prefer_dayfirst = false,
+            Long("strict") => args.options.strict = true,
+            Long("min-confidence") => args.options.min_confidence = parser.value()?.parse()?,
+            Long("skip-nulls") => args.options.skip_nulls = true,
+            Long("explain") => args.explain = true,
+            Short('h') | Long("help") => return Ok(None),
+            Value(path) if args.path.is_none() => args.path = Some(path.string()?),
+            _ => return Err(arg.unexpected()),
+        }
+    }
+    Ok(Some(args))
+}
+
+fn open_input(path: Option<&str>) -> io::Result<Box<dyn BufRead>> {
+    match path {
+        None | Some("-") => Ok(Box::new(io::stdin().lock())),
+        Some(path) => {
+            let file = File::open(path).map_err(|e| io::Error::new(e.kind(), format!("{path}: {e}")))?;
+            Ok(Box::new(BufReader::new(file)))
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let input = open_input(args.path.as_deref())?;
+    if !args.explain {
+        print_result(&infer_from_reader(input, &args.options)?);
+        return Ok(());
+    }
+
+    // The breakdown needs every line in memory
+    let lines: Vec<String> = input
+        .lines()
+        .filter(|line| line.as_ref().map_or(true, |l| !l.trim().is_empty()))
+        .collect::<io::Result<_>>()?;
+    let result = infer_with_options(&lines, &args.options)?;
+    print_result(&result);
+    println!();
+    for (pos, analysis) in analyze_with_options(&lines, &args.options).iter().enumerate() {
+        let resolved = result.token_types.get(pos).map_or_else(String::new, |t| t.tag());
+        let candidates: Vec<String> = analysis
+            .candidates
+            .iter()
+            .map(|(token_type, votes)| format!("{}={}", token_type.tag(), votes))
+            .collect();
+        println!("{:>3}  {:<16} {}", pos, resolved, candidates.join(" "));
+    }
+    Ok(())
+}
+
+fn print_result(result: &InferResult) {
+    println!("format: {}", result.format);
+    println!("confidence: {:.3}", result.confidence);
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
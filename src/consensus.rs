@@ -1,16 +1,165 @@
 //! Consensus-based resolution of ambiguous date tokens
 
-use crate::constraints::TokenType;
+use crate::constraints::{is_tz_abbreviation, TokenType};
 use crate::error::{DateInferError, Result};
+use crate::format::UnknownPolicy;
 use crate::tokenizer::Token;
 use crate::InferOptions;
-use rustc_hash::FxHashMap;
+use smallvec::SmallVec;
+
+/// Vote counts per token position
+///
+/// Callers own the buffer so its counters can be reused across calls.
+pub type PositionVotes = Vec<VoteCounter>;
+
+/// Every token type except separators, in [`vote_slot`] order
+const VOTE_TYPES: [TokenType; 26] = [
+    TokenType::Year4,
+    TokenType::Year2,
+    TokenType::Month,
+    TokenType::Day,
+    TokenType::MonthName,
+    TokenType::MonthNameShort,
+    TokenType::DayOfYear,
+    TokenType::WeekdayName,
+    TokenType::WeekdayShort,
+    TokenType::WeekdayNumber,
+    TokenType::WeekdayNumberFromSunday,
+    TokenType::IsoYear,
+    TokenType::IsoYear2,
+    TokenType::IsoWeek,
+    TokenType::Hour24,
+    TokenType::Hour12,
+    TokenType::Minute,
+    TokenType::Second,
+    TokenType::Subsecond,
+    TokenType::AmPm,
+    TokenType::TimeWord,
+    TokenType::TzOffset,
+    TokenType::TzName,
+    TokenType::TzZ,
+    TokenType::DayOrMonth,
+    TokenType::Unknown,
+];
+
+/// Index of a token type in [`VOTE_TYPES`] (`None` for separators, which
+/// carry a character and are counted separately)
+fn vote_slot(token_type: TokenType) -> Option<usize> {
+    let slot = match token_type {
+        TokenType::Year4 => 0,
+        TokenType::Year2 => 1,
+        TokenType::Month => 2,
+        TokenType::Day => 3,
+        TokenType::MonthName => 4,
+        TokenType::MonthNameShort => 5,
+        TokenType::DayOfYear => 6,
+        TokenType::WeekdayName => 7,
+        TokenType::WeekdayShort => 8,
+        TokenType::WeekdayNumber => 9,
+        TokenType::WeekdayNumberFromSunday => 10,
+        TokenType::IsoYear => 11,
+        TokenType::IsoYear2 => 12,
+        TokenType::IsoWeek => 13,
+        TokenType::Hour24 => 14,
+        TokenType::Hour12 => 15,
+        TokenType::Minute => 16,
+        TokenType::Second => 17,
+        TokenType::Subsecond => 18,
+        TokenType::AmPm => 19,
+        TokenType::TimeWord => 20,
+        TokenType::TzOffset => 21,
+        TokenType::TzName => 22,
+        TokenType::TzZ => 23,
+        TokenType::DayOrMonth => 24,
+        TokenType::Unknown => 25,
+        TokenType::Separator(_) => return None,
+    };
+    Some(slot)
+}
+
+/// Vote counts for one position: a fixed array indexed by [`vote_slot`],
+/// so counting never hashes, plus the separator characters seen there
+#[derive(Debug, Default, Clone)]
+pub struct VoteCounter {
+    counts: [usize; VOTE_TYPES.len()],
+    separators: SmallVec<[(char, usize); 2]>,
+}
+
+impl VoteCounter {
+    /// Count one vote for `token_type`
+    fn add(&mut self, token_type: TokenType) {
+        match vote_slot(token_type) {
+            Some(slot) => self.counts[slot] += 1,
+            None => {
+                let TokenType::Separator(c) = token_type else { return };
+                match self.separators.iter_mut().find(|(s, _)| *s == c) {
+                    Some((_, count)) => *count += 1,
+                    None => self.separators.push((c, 1)),
+                }
+            }
+        }
+    }
+
+    /// Number of votes for `token_type`
+    fn count(&self, token_type: TokenType) -> usize {
+        match (vote_slot(token_type), token_type) {
+            (Some(slot), _) => self.counts[slot],
+            (None, TokenType::Separator(c)) => {
+                self.separators.iter().find(|(s, _)| *s == c).map_or(0, |&(_, count)| count)
+            }
+            (None, _) => 0,
+        }
+    }
+
+    /// Whether any example voted for `token_type`
+    fn contains(&self, token_type: TokenType) -> bool {
+        self.count(token_type) > 0
+    }
+
+    /// Types with at least one vote, with their counts
+    fn iter(&self) -> impl Iterator<Item = (TokenType, usize)> + '_ {
+        let typed = VOTE_TYPES.iter().zip(self.counts).filter(|&(_, count)| count > 0).map(|(&t, count)| (t, count));
+        typed.chain(self.separators.iter().map(|&(c, count)| (TokenType::Separator(c), count)))
+    }
+
+    fn clear(&mut self) {
+        self.counts = [0; VOTE_TYPES.len()];
+        self.separators.clear();
+    }
+}
 
 /// Resolve token types across all examples using consensus voting
+///
+/// Also reports whether the day/month order came from
+/// [`InferOptions::prefer_dayfirst`] rather than from the data.
 pub fn resolve_consensus(
     tokenized_dates: &[Vec<Token>],
     options: &InferOptions,
-) -> Result<(Vec<TokenType>, f64)> {
+    votes: &mut PositionVotes,
+) -> Result<(Vec<TokenType>, f64, bool)> {
+    let (resolved, position_confidences, used_preference) =
+        resolve_consensus_by_position(tokenized_dates, options, votes)?;
+
+    // Overall confidence is the mean over resolved, non-separator positions
+    let scored: Vec<f64> = position_confidences.into_iter().flatten().collect();
+    let overall_confidence = if scored.is_empty() {
+        0.0
+    } else {
+        scored.iter().sum::<f64>() / scored.len() as f64
+    };
+
+    Ok((resolved, overall_confidence, used_preference))
+}
+
+/// Resolve token types, with the share of examples supporting each
+/// position's type (`None` for separators and literal text every example
+/// agrees on), and whether the day/month order came from the
+/// preference
+pub fn resolve_consensus_by_position(
+    tokenized_dates: &[Vec<Token>],
+    options: &InferOptions,
+    votes: &mut PositionVotes,
+) -> Result<(Vec<TokenType>, Vec<Option<f64>>, bool)> {
     if tokenized_dates.is_empty() {
         return Err(DateInferError::EmptyInput);
     }
@@ -19,27 +168,8 @@ pub fn resolve_consensus(
     let num_examples = tokenized_dates.len();
 
     // Phase 2: Collect constraints from all examples for each position
-    let mut position_votes: Vec<FxHashMap<TokenType, usize>> = vec![FxHashMap::default(); num_positions];
-    let mut position_constraints: Vec<PositionConstraint> = vec![PositionConstraint::default(); num_positions];
-
-    for tokens in tokenized_dates {
-        for (pos, token) in tokens.iter().enumerate() {
-            // Track if any example at this position MUST be a specific type
-            if token.must_be_day() {
-                position_constraints[pos].must_be_day = true;
-            }
-
-            // Vote for each possible type
-            for token_type in &token.possible_types {
-                *position_votes[pos].entry(*token_type).or_insert(0) += 1;
-            }
-
-            // Track the separator character if present
-            if let Some(TokenType::Separator(c)) = token.possible_types.iter().find(|t| matches!(t, TokenType::Separator(_))) {
-                position_constraints[pos].separator = Some(*c);
-            }
-        }
-    }
+    let position_constraints = collect_votes(tokenized_dates, votes);
+    let position_votes: &[VoteCounter] = votes;
 
     // Detect time sequences: positions connected by : or . separators
     // Time patterns: HH:MM:SS or HH.MM.SS (must be connected sequence)
@@ -51,7 +181,7 @@ pub fn resolve_consensus(
         // Check if this starts a time sequence (X:Y or X.Y)
         if i + 2 < num_positions {
             let sep = position_constraints.get(i + 1).and_then(|c| c.separator);
-            if sep == Some(':') || sep == Some('.') {
+            if matches!(sep, Some(':' | '.' | '-')) {
                 // Found potential time start. Check if it's actually time by looking for
                 // consistent separators (: or .) in a sequence
                 let mut time_positions = vec![i, i + 2];
@@ -71,13 +201,50 @@ pub fn resolve_consensus(
 
                 // Only mark as time if:
                 // 1. Using colon (always time), OR
-                // 2. Using dot AND there's a space before (date/time boundary)
-                let is_after_space = i > 0 && (0..i).any(|p| {
-                    position_constraints.get(p).and_then(|c| c.separator) == Some(' ')
+                // 2. Using dot AND every value in the group is a valid
+                //    hour/minute/second AND either
+                //    a. the group directly follows a space or 'T' (date/time
+                //       boundary) after a date component, or
+                //    b. it is a full HH.MM.SS group leading the string and a
+                //       space-separated part after it holds the year
+                //       ("14.30.05 15.03.2025").
+                //    This keeps "Mon 15.03.2025" and "2025.01.15" as dates.
+                // 3. Using dash for a full HH-MM-SS group of valid values
+                //    after a space or 'T' that ends a complete date
+                //    ("2025-01-15 10-30-00").
+                let boundary = if i > 0 { position_constraints[i - 1].separator } else { None };
+                let follows_date = (0..i).any(|p| {
+                    position_constraints[p].separator.is_none()
+                        && !position_votes[p].contains(TokenType::WeekdayName)
+                        && !position_votes[p].contains(TokenType::WeekdayShort)
+                });
+                let all_time_valid = time_positions.iter().enumerate().all(|(k, &p)| {
+                    let time_type = if k == 0 { TokenType::Hour24 } else { TokenType::Minute };
+                    position_votes[p].count(time_type) == num_examples
                 });
-                let is_after_t = i > 0 && position_constraints.get(i - 1).and_then(|c| c.separator) == Some('T');
+                let end = *time_positions.last().unwrap();
+                let precedes_date = i == 0
+                    && time_positions.len() == 3
+                    && position_constraints.get(end + 1).and_then(|c| c.separator) == Some(' ')
+                    && (end + 2..num_positions)
+                        .any(|p| position_votes[p].count(TokenType::Year4) == num_examples);
+                let is_dot_time = first_sep == '.'
+                    && all_time_valid
+                    && ((matches!(boundary, Some(' ') | Some('T')) && follows_date) || precedes_date);
+                let date_components = (0..i)
+                    .filter(|&p| {
+                        position_constraints[p].separator.is_none()
+                            && !position_votes[p].contains(TokenType::WeekdayName)
+                            && !position_votes[p].contains(TokenType::WeekdayShort)
+                    })
+                    .count();
+                let is_dash_time = first_sep == '-'
+                    && all_time_valid
+                    && time_positions.len() == 3
+                    && matches!(boundary, Some(' ') | Some('T'))
+                    && date_components >= 3;
 
-                if first_sep == ':' || is_after_space || is_after_t {
+                if first_sep == ':' || is_dot_time || is_dash_time {
                     for &pos in &time_positions {
                         is_time_position[pos] = true;
                     }
@@ -92,7 +259,7 @@ pub fn resolve_consensus(
     // Detect subsecond positions: numeric after '.' separator following a time position
     let mut is_subsecond_position: Vec<bool> = vec![false; num_positions];
     for pos in 2..num_positions {
-        if position_votes[pos].contains_key(&TokenType::Subsecond)
+        if position_votes[pos].contains(TokenType::Subsecond)
             && position_constraints[pos - 1].separator == Some('.')
             && is_time_position[pos - 2]
         {
@@ -100,6 +267,135 @@ pub fn resolve_consensus(
         }
     }
 
+    // Detect unlisted timezone abbreviations ("PDT", "AEST", "JST"): an
+    // uppercase 2-5 letter word in every example, directly after a time
+    let mut is_tz_abbrev_position: Vec<bool> = vec![false; num_positions];
+    for pos in 0..num_positions {
+        let prev = (0..pos).rev().find(|&p| position_constraints[p].separator.is_none());
+        let after_time = prev.is_some_and(|p| is_time_position[p] || is_subsecond_position[p]);
+        if after_time
+            && tokenized_dates.iter().all(|tokens| {
+                is_tz_abbreviation(&tokens[pos].value)
+                    && tokens[pos].possible_types.iter().all(|t| matches!(t, TokenType::Unknown | TokenType::TzName))
+            })
+        {
+            is_tz_abbrev_position[pos] = true;
+        }
+
+        // An uppercase month abbreviation ending the string after a time
+        // ("2025-01-15 10:30:00 MAR") is a timezone when the date already
+        // has a year and a numeric day and month
+        let terminal = (pos + 1..num_positions).all(|p| position_constraints[p].separator.is_some());
+        let numeric_date_positions = (0..pos)
+            .filter(|&p| {
+                position_constraints[p].separator.is_none()
+                    && !is_time_position[p]
+                    && !is_subsecond_position[p]
+                    && tokenized_dates.iter().all(|tokens| tokens[p].numeric_value.is_some())
+            })
+            .count();
+        if after_time
+            && terminal
+            && numeric_date_positions >= 3
+            && tokenized_dates.iter().all(|tokens| {
+                is_tz_abbreviation(&tokens[pos].value) && tokens[pos].possible_types.contains(&TokenType::MonthNameShort)
+            })
+        {
+            is_tz_abbrev_position[pos] = true;
+        }
+    }
+
+    // Detect ordinal days (2025-045, 2025-5): a 1-366 value directly after a
+    // year, with at least one 3-digit example proving it isn't a month
+    let mut is_day_of_year_position: Vec<bool> = vec![false; num_positions];
+    for pos in 2..num_positions {
+        if position_votes[pos].contains(TokenType::DayOfYear)
+            && !is_subsecond_position[pos]
+            && position_constraints[pos - 1].separator.is_some()
+            && position_votes[pos - 2].count(TokenType::Year4) == num_examples
+            && tokenized_dates.iter().all(|tokens| {
+                tokens[pos].value.len() <= 3 && tokens[pos].numeric_value.is_some_and(|v| (1..=366).contains(&v))
+            })
+        {
+            is_day_of_year_position[pos] = true;
+        }
+    }
+
+    // Detect ISO week dates (2025-W03, 25-W03, 2025W03-1): a 01-53 value
+    // directly after a literal `W` that follows a 4- or 2-digit year, and an
+    // optional 1-7 weekday digit after it
+    let mut is_iso_week_position: Vec<bool> = vec![false; num_positions];
+    let mut is_iso_weekday_position: Vec<bool> = vec![false; num_positions];
+    let mut iso_year_position: Vec<Option<TokenType>> = vec![None; num_positions];
+    for pos in 2..num_positions {
+        let is_week = tokenized_dates.iter().all(|tokens| {
+            tokens[pos - 1].value == "W"
+                && tokens[pos].value.len() == 2
+                && tokens[pos].numeric_value.is_some_and(|v| (1..=53).contains(&v))
+        });
+        let year_pos = (0..pos - 1).rev().find(|&p| position_constraints[p].separator.is_none());
+        let year_type = year_pos.and_then(|p| {
+            let all_vote = |t: TokenType| position_votes[p].count(t) == num_examples;
+            if all_vote(TokenType::Year4) {
+                Some(TokenType::IsoYear)
+            } else if all_vote(TokenType::Year2) && tokenized_dates.iter().all(|tokens| tokens[p].value.len() == 2) {
+                Some(TokenType::IsoYear2)
+            } else {
+                None
+            }
+        });
+        if let (true, Some(year_pos), Some(year_type)) = (is_week, year_pos, year_type) {
+            iso_year_position[year_pos] = Some(year_type);
+            is_iso_week_position[pos] = true;
+            let next = if position_constraints.get(pos + 1).is_some_and(|c| c.separator == Some('-')) {
+                pos + 2
+            } else {
+                pos + 1
+            };
+            if next < num_positions
+                && tokenized_dates.iter().all(|tokens| {
+                    tokens[next].value.len() == 1 && tokens[next].numeric_value.is_some_and(|v| (1..=7).contains(&v))
+                })
+            {
+                is_iso_weekday_position[next] = true;
+            }
+        }
+    }
+
+    // Detect bare hours before an AM/PM marker ("3 PM", "3PM"), which have
+    // no ':' group for the time detector to find
+    let mut is_ampm_hour_position: Vec<bool> = vec![false; num_positions];
+    for pos in 0..num_positions {
+        let next = (pos + 1..num_positions).find(|&p| position_constraints[p].separator.is_none());
+        let gap_ok = next.is_some_and(|n| (pos + 1..n).all(|p| position_constraints[p].separator == Some(' ')));
+        if !is_time_position[pos]
+            && position_votes[pos].count(TokenType::Hour12) == num_examples
+            && gap_ok
+            && next.is_some_and(|n| position_votes[n].contains(TokenType::AmPm))
+        {
+            is_ampm_hour_position[pos] = true;
+        }
+    }
+
+    // Detect a year written with 2 digits in some examples and 4 in the
+    // others (15/03/25, 15/03/2025); the majority width wins, ties to %Y.
+    // Only the slot with the most 4-digit years qualifies, so a stray
+    // year-first row doesn't turn the day slot into a year.
+    let year4_votes = |p: usize| position_votes[p].count(TokenType::Year4);
+    let mut mixed_year_position: Vec<Option<TokenType>> = vec![None; num_positions];
+    for pos in 0..num_positions {
+        let year4 = year4_votes(pos);
+        let year2 = position_votes[pos].count(TokenType::Year2);
+        if year4 > 0
+            && year2 > 0
+            && year4 + year2 == num_examples
+            && !is_time_position[pos]
+            && (0..num_positions).all(|p| p == pos || year4_votes(p) < year4)
+        {
+            mixed_year_position[pos] = Some(if year4 >= year2 { TokenType::Year4 } else { TokenType::Year2 });
+        }
+    }
+
     // Detect likely Year2 position (last DATE numeric position, not time)
     let mut likely_year2_pos: Option<usize> = None;
 
@@ -109,26 +405,34 @@ pub fn resolve_consensus(
             position_constraints[pos].separator.is_none()
                 && !is_time_position[pos]
                 && !is_subsecond_position[pos]
-                && !position_votes[pos].contains_key(&TokenType::MonthName)
-                && !position_votes[pos].contains_key(&TokenType::MonthNameShort)
-                && !position_votes[pos].contains_key(&TokenType::WeekdayName)
-                && !position_votes[pos].contains_key(&TokenType::WeekdayShort)
-                && !position_votes[pos].contains_key(&TokenType::TzName)
-                && !position_votes[pos].contains_key(&TokenType::TzZ)
-                && !position_votes[pos].contains_key(&TokenType::TzOffset)
-                && !position_votes[pos].contains_key(&TokenType::AmPm)
+                && !is_tz_abbrev_position[pos]
+                && !is_day_of_year_position[pos]
+                && !is_iso_week_position[pos]
+                && !is_iso_weekday_position[pos]
+                && iso_year_position[pos].is_none()
+                && !is_ampm_hour_position[pos]
+                && !position_votes[pos].contains(TokenType::MonthName)
+                && !position_votes[pos].contains(TokenType::MonthNameShort)
+                && !position_votes[pos].contains(TokenType::WeekdayName)
+                && !position_votes[pos].contains(TokenType::WeekdayShort)
+                && !position_votes[pos].contains(TokenType::TzName)
+                && !position_votes[pos].contains(TokenType::TzZ)
+                && !position_votes[pos].contains(TokenType::TzOffset)
+                && !position_votes[pos].contains(TokenType::AmPm)
+                && !position_votes[pos].contains(TokenType::TimeWord)
         })
         .collect();
 
     // Check if there's a month name
     let has_month_name = (0..num_positions).any(|p| {
-        position_votes[p].contains_key(&TokenType::MonthName)
-            || position_votes[p].contains_key(&TokenType::MonthNameShort)
+        !is_tz_abbrev_position[p]
+            && (position_votes[p].contains(TokenType::MonthName)
+                || position_votes[p].contains(TokenType::MonthNameShort))
     });
 
     // Check if Year4 exists anywhere (if so, don't use Year2)
     let has_year4 = (0..num_positions).any(|p| {
-        position_votes[p].contains_key(&TokenType::Year4)
+        position_votes[p].contains(TokenType::Year4)
     });
 
     // Find the last position that could be Year2
@@ -137,9 +441,19 @@ pub fn resolve_consensus(
     // Without month name: need at least 3 numeric positions (day + month + year)
     let min_numeric_for_year = if has_month_name { 2 } else { 3 };
 
-    if let Some(&last_pos) = numeric_positions.last() {
+    // A leading slot that saw a 2-digit value above 31 can only be the
+    // year, so the date is year-first (99/01/15)
+    let leading_year2 = numeric_positions
+        .first()
+        .copied()
+        .filter(|&first| position_constraints[first].must_be_year2);
+    if let Some(first_pos) = leading_year2 {
+        if numeric_positions.len() >= min_numeric_for_year && !has_year4 {
+            likely_year2_pos = Some(first_pos);
+        }
+    } else if let Some(&last_pos) = numeric_positions.last() {
         // Set Year2 if: has Year2 votes, enough numeric positions, and no Year4 elsewhere
-        if position_votes[last_pos].contains_key(&TokenType::Year2)
+        if position_votes[last_pos].contains(TokenType::Year2)
             && numeric_positions.len() >= min_numeric_for_year
             && !has_year4
         {
@@ -149,8 +463,6 @@ pub fn resolve_consensus(
 
     // Phase 3: Determine resolved type for each position
     let mut resolved: Vec<TokenType> = Vec::with_capacity(num_positions);
-    let mut total_confidence: f64 = 0.0;
-    let mut confidence_count = 0;
 
     // Track which positions have been assigned Day and Month
     let mut day_assigned: Option<usize> = None;
@@ -159,11 +471,50 @@ pub fn resolve_consensus(
     // Track time sequence state
     let mut time_component_index = 0; // 0=Hour, 1=Minute, 2=Second
 
+    // A bare two-part time whose first slot exceeds 23 in most examples is
+    // minutes and seconds (45:30), as in a duration; a few such rows are
+    // dirty hours that lower the confidence instead. So is a bare time
+    // carrying a fraction (10:30.123), since a fraction belongs to seconds.
+    // After a date the pair keeps its hour (2025-01-15 10:30.123)
+    let time_positions: Vec<usize> = (0..num_positions).filter(|&p| is_time_position[p]).collect();
+    if let [0, second] = time_positions[..] {
+        let count = |t: TokenType| position_votes[0].count(t);
+        let above_23 = count(TokenType::Hour24) * 2 < tokenized_dates.len();
+        let fractional = is_subsecond_position.get(second + 2).copied().unwrap_or(false);
+        if (above_23 || fractional) && count(TokenType::Minute) == tokenized_dates.len() {
+            time_component_index = 1;
+        }
+    }
+
+    // Set when no example proves the day/month order and prefer_dayfirst decides
+    let mut used_preference = false;
+
     // First pass: resolve unambiguous positions
     for pos in 0..num_positions {
         let votes = &position_votes[pos];
         let constraint = &position_constraints[pos];
 
+        // Pinned positions override votes
+        if let Some(&(_, pinned)) = options.pinned.iter().find(|&&(index, _)| index == pos) {
+            if is_time_position[pos] {
+                time_component_index += 1;
+            }
+            let assigned = match pinned {
+                TokenType::Day => &mut day_assigned,
+                TokenType::Month | TokenType::MonthName | TokenType::MonthNameShort => &mut month_assigned,
+                _ => {
+                    resolved.push(pinned);
+                    continue;
+                }
+            };
+            if assigned.is_some() {
+                return Err(DateInferError::UnresolvableAmbiguity);
+            }
+            *assigned = Some(pos);
+            resolved.push(pinned);
+            continue;
+        }
+
         // Handle separators
         if let Some(c) = constraint.separator {
             resolved.push(TokenType::Separator(c));
@@ -188,6 +539,34 @@ pub fn resolve_consensus(
             continue;
         }
 
+        if is_day_of_year_position[pos] {
+            resolved.push(TokenType::DayOfYear);
+            continue;
+        }
+
+        if is_ampm_hour_position[pos] {
+            resolved.push(TokenType::Hour12);
+            continue;
+        }
+
+        if let Some(year_type) = iso_year_position[pos] {
+            resolved.push(year_type);
+            continue;
+        }
+        if is_iso_week_position[pos] {
+            resolved.push(TokenType::IsoWeek);
+            continue;
+        }
+        if is_iso_weekday_position[pos] {
+            resolved.push(TokenType::WeekdayNumber);
+            continue;
+        }
+
+        if let Some(year_type) = mixed_year_position[pos] {
+            resolved.push(year_type);
+            continue;
+        }
+
         // Handle Year2 position (detected as last position after month name)
         if likely_year2_pos == Some(pos) {
             resolved.push(TokenType::Year2);
@@ -196,14 +575,26 @@ pub fn resolve_consensus(
 
         // Handle positions that MUST be a specific type
         if constraint.must_be_day {
+            // Two slots that each saw a value > 12 cannot both be the day
+            if day_assigned.is_some() {
+                return Err(DateInferError::UnresolvableAmbiguity);
+            }
             resolved.push(TokenType::Day);
             day_assigned = Some(pos);
             continue;
         }
 
         // Check for unambiguous text tokens (month names, weekday names, etc.)
-        if votes.contains_key(&TokenType::MonthName) || votes.contains_key(&TokenType::MonthNameShort) {
-            let month_type = if votes.contains_key(&TokenType::MonthName) {
+        let is_weekday = is_weekday_slot(position_votes, &position_constraints, pos);
+        if !is_weekday
+            && !is_tz_abbrev_position[pos]
+            && (votes.contains(TokenType::MonthName) || votes.contains(TokenType::MonthNameShort))
+        {
+            // Mixed full and short names follow the majority; ties go to
+            // %B, which glibc and chrono also accept for abbreviations
+            let full = votes.count(TokenType::MonthName);
+            let short = votes.count(TokenType::MonthNameShort);
+            let month_type = if full >= short {
                 TokenType::MonthName
             } else {
                 TokenType::MonthNameShort
@@ -212,37 +603,41 @@ pub fn resolve_consensus(
             month_assigned = Some(pos);
             continue;
         }
-        if votes.contains_key(&TokenType::WeekdayName) {
+        if votes.contains(TokenType::WeekdayName) {
             resolved.push(TokenType::WeekdayName);
             continue;
         }
-        if votes.contains_key(&TokenType::WeekdayShort) {
+        if votes.contains(TokenType::WeekdayShort) {
             resolved.push(TokenType::WeekdayShort);
             continue;
         }
-        if votes.contains_key(&TokenType::TzName) {
+        if votes.contains(TokenType::TzName) || is_tz_abbrev_position[pos] {
             resolved.push(TokenType::TzName);
             continue;
         }
-        if votes.contains_key(&TokenType::TzZ) {
+        if votes.contains(TokenType::TzZ) {
             resolved.push(TokenType::TzZ);
             continue;
         }
-        if votes.contains_key(&TokenType::TzOffset) {
+        if votes.contains(TokenType::TzOffset) {
             resolved.push(TokenType::TzOffset);
             continue;
         }
-        if votes.contains_key(&TokenType::AmPm) {
+        if votes.contains(TokenType::AmPm) {
             resolved.push(TokenType::AmPm);
             continue;
         }
+        if votes.contains(TokenType::TimeWord) {
+            resolved.push(TokenType::TimeWord);
+            continue;
+        }
 
         // Check for year
-        if votes.contains_key(&TokenType::Year4) {
+        if votes.contains(TokenType::Year4) {
             resolved.push(TokenType::Year4);
             continue;
         }
-        if votes.contains_key(&TokenType::Year2) && !votes.contains_key(&TokenType::DayOrMonth) {
+        if votes.contains(TokenType::Year2) && !votes.contains(TokenType::DayOrMonth) {
             resolved.push(TokenType::Year2);
             continue;
         }
@@ -260,7 +655,7 @@ pub fn resolve_consensus(
         let votes = &position_votes[pos];
 
         // If this position has DayOrMonth votes
-        if votes.contains_key(&TokenType::DayOrMonth) || votes.contains_key(&TokenType::Day) {
+        if votes.contains(TokenType::DayOrMonth) || votes.contains(TokenType::Day) {
             // If we already assigned a month elsewhere, this must be day
             if month_assigned.is_some() && day_assigned.is_none() {
                 resolved[pos] = TokenType::Day;
@@ -281,12 +676,20 @@ pub fn resolve_consensus(
                 let other_ambiguous: Vec<usize> = (0..num_positions)
                     .filter(|&p| p != pos && resolved[p] == TokenType::Unknown)
                     .filter(|&p| {
-                        position_votes[p].contains_key(&TokenType::DayOrMonth)
-                            || position_votes[p].contains_key(&TokenType::Day)
+                        position_votes[p].contains(TokenType::DayOrMonth)
+                            || position_votes[p].contains(TokenType::Day)
                     })
                     .collect();
 
-                if options.prefer_dayfirst {
+                // A year before both slots means ISO order (YYYY-MM-DD);
+                // YYYY-DD-MM is not used in practice, so ignore the preference
+                let year_first = resolved[..pos].iter().any(|t| matches!(t, TokenType::Year4 | TokenType::Year2));
+                // A lone slot next to a year is a year-month date (2025-01, 01/2025)
+                let year_month = other_ambiguous.is_empty()
+                    && resolved.iter().any(|t| matches!(t, TokenType::Year4 | TokenType::Year2));
+                used_preference = !year_first && !year_month;
+
+                if options.prefer_dayfirst && !year_first && !year_month {
                     // First ambiguous position is day
                     resolved[pos] = TokenType::Day;
                     day_assigned = Some(pos);
@@ -313,16 +716,36 @@ pub fn resolve_consensus(
             }
         }
 
+        // A single-digit slot left over after the date is a weekday number
+        if options.weekday_numbers && day_assigned.is_some() && month_assigned.is_some() {
+            let values: Option<Vec<u32>> = tokenized_dates
+                .iter()
+                .map(|tokens| tokens[pos].numeric_value.filter(|_| tokens[pos].value.len() == 1))
+                .collect();
+            if let Some(values) = values.filter(|v| v.iter().all(|&d| d <= 7)) {
+                let has_zero = values.contains(&0);
+                let has_seven = values.contains(&7);
+                if !(has_zero && has_seven) {
+                    resolved[pos] = if has_zero {
+                        TokenType::WeekdayNumberFromSunday
+                    } else {
+                        TokenType::WeekdayNumber
+                    };
+                    continue;
+                }
+            }
+        }
+
         // Check for time components
-        if votes.contains_key(&TokenType::Hour24) {
+        if votes.contains(TokenType::Hour24) {
             resolved[pos] = TokenType::Hour24;
             continue;
         }
-        if votes.contains_key(&TokenType::Minute) {
+        if votes.contains(TokenType::Minute) {
             resolved[pos] = TokenType::Minute;
             continue;
         }
-        if votes.contains_key(&TokenType::Second) {
+        if votes.contains(TokenType::Second) {
             resolved[pos] = TokenType::Second;
             continue;
         }
@@ -332,8 +755,25 @@ pub fn resolve_consensus(
     }
 
     // Calculate confidence
+    let mut position_confidences: Vec<Option<f64>> = vec![None; num_positions];
     for pos in 0..num_positions {
-        if matches!(resolved[pos], TokenType::Separator(_) | TokenType::Unknown) {
+        // Separators only count when examples disagree on them
+        if let TokenType::Separator(_) = resolved[pos] {
+            let misspelled = majority_spelling(tokenized_dates, pos, resolved[pos]).map_or(0, |(_, n)| n);
+            let agreeing = position_votes[pos].count(resolved[pos]) - misspelled;
+            if agreeing < num_examples {
+                position_confidences[pos] = Some(agreeing as f64 / num_examples as f64);
+            }
+            continue;
+        }
+        // So does literal text, scored by the examples sharing the text
+        // emitted for it; a wildcard matches whatever the text is
+        if resolved[pos] == TokenType::Unknown {
+            let literal = &tokenized_dates[0][pos].value;
+            let agreeing = tokenized_dates.iter().filter(|tokens| tokens[pos].value == *literal).count();
+            if agreeing < num_examples && options.unknown_policy != UnknownPolicy::Wildcard {
+                position_confidences[pos] = Some(agreeing as f64 / num_examples as f64);
+            }
             continue;
         }
 
@@ -341,34 +781,238 @@ pub fn resolve_consensus(
         let resolved_type = &resolved[pos];
 
         // Count how many examples support this resolution
-        let supporting = votes.get(resolved_type).copied().unwrap_or(0);
+        let supporting = if is_tz_abbrev_position[pos]
+            || is_day_of_year_position[pos]
+            || is_iso_week_position[pos]
+            || matches!(resolved_type, TokenType::WeekdayNumber | TokenType::WeekdayNumberFromSunday)
+        {
+            // Every example has the abbreviation shape, a 1-366 value
+            // whatever its width, a checked week or a checked weekday digit
+            num_examples
+        } else if *resolved_type == TokenType::IsoYear {
+            votes.count(TokenType::Year4)
+        } else if *resolved_type == TokenType::IsoYear2 {
+            votes.count(TokenType::Year2)
+        } else {
+            votes.count(*resolved_type)
+        };
 
         // For DayOrMonth resolved to Day or Month, also count DayOrMonth votes
         // but cap at num_examples to avoid double-counting
         let supporting = if *resolved_type == TokenType::Day || *resolved_type == TokenType::Month {
-            let total = supporting + votes.get(&TokenType::DayOrMonth).copied().unwrap_or(0);
+            let total = supporting + votes.count(TokenType::DayOrMonth);
             total.min(num_examples) // Cap to avoid confidence > 1.0
         } else {
             supporting
         };
 
-        let position_confidence = supporting as f64 / num_examples as f64;
-        total_confidence += position_confidence;
-        confidence_count += 1;
+        // Examples spelling the token differently ("Jan." in a "Jan"
+        // column) don't fit the format either
+        let supporting = match majority_spelling(tokenized_dates, pos, *resolved_type) {
+            Some((_, misspelled)) => supporting.saturating_sub(misspelled),
+            None => supporting,
+        };
+
+        // A day/month order picked by preference is a coin flip between
+        // the two orders, however many examples fit it
+        let share = supporting as f64 / num_examples as f64;
+        position_confidences[pos] = Some(if used_preference && matches!(resolved_type, TokenType::Day | TokenType::Month) {
+            share / 2.0
+        } else {
+            share
+        });
     }
 
-    let overall_confidence = if confidence_count > 0 {
-        total_confidence / confidence_count as f64
-    } else {
-        0.0
+    Ok((resolved, position_confidences, used_preference))
+}
+
+/// Decide whether a text slot holding weekday votes is the weekday rather
+/// than the month name
+///
+/// A slot voted both ways (`Mon` / `May`) is settled in order of:
+/// 1. a month name at another position (a date names its month once),
+/// 2. the majority of examples,
+/// 3. position: a leading slot is the weekday, any other slot the month.
+fn is_weekday_slot(
+    position_votes: &[VoteCounter],
+    position_constraints: &[PositionConstraint],
+    pos: usize,
+) -> bool {
+    // "May" votes for both month types, so take the larger count rather
+    // than the sum
+    let count = |p: usize, types: [TokenType; 2]| -> usize {
+        types.iter().map(|t| position_votes[p].count(*t)).max().unwrap_or(0)
     };
+    let months = [TokenType::MonthName, TokenType::MonthNameShort];
+    let weekdays = [TokenType::WeekdayName, TokenType::WeekdayShort];
 
-    Ok((resolved, overall_confidence))
+    let weekday_votes = count(pos, weekdays);
+    let month_votes = count(pos, months);
+    if weekday_votes == 0 || month_votes == 0 {
+        return weekday_votes > 0;
+    }
+
+    if (0..position_votes.len()).any(|p| p != pos && count(p, months) > 0) {
+        return true;
+    }
+    if weekday_votes != month_votes {
+        return weekday_votes > month_votes;
+    }
+    position_constraints[..pos].iter().all(|c| c.separator.is_some())
+}
+
+/// Count votes into `position_votes`, clearing its maps but keeping their
+/// allocations, and return the per-position constraints
+fn collect_votes(tokenized_dates: &[Vec<Token>], position_votes: &mut PositionVotes) -> Vec<PositionConstraint> {
+    let num_positions = tokenized_dates.first().map_or(0, |t| t.len());
+    position_votes.resize_with(num_positions, VoteCounter::default);
+    for votes in position_votes.iter_mut() {
+        votes.clear();
+    }
+    let mut position_constraints: Vec<PositionConstraint> = vec![PositionConstraint::default(); num_positions];
+
+    for tokens in tokenized_dates {
+        for (pos, token) in tokens.iter().enumerate() {
+            // Track if any example at this position MUST be a specific type
+            if token.must_be_day() {
+                position_constraints[pos].must_be_day = true;
+            }
+            if token.must_be_year2() {
+                position_constraints[pos].must_be_year2 = true;
+            }
+
+            // Vote for each possible type
+            for token_type in &token.possible_types {
+                position_votes[pos].add(*token_type);
+            }
+
+        }
+    }
+
+    // The separator at each position is the majority one; ties go to the
+    // separator of the first example
+    for (pos, votes) in position_votes.iter().enumerate() {
+        let first = tokenized_dates[0][pos].possible_types.first().copied();
+        position_constraints[pos].separator = votes
+            .separators
+            .iter()
+            .max_by_key(|&&(c, count)| (count, Some(TokenType::Separator(c)) == first))
+            .map(|&(c, _)| c);
+    }
+
+    position_constraints
+}
+
+/// How an example spells a token whose type doesn't fix its text: a
+/// separator's run (`/` or `//`; strptime reads any run of whitespace, so
+/// spaces have none), and an abbreviated month name with (`"."`) or
+/// without (`""`) a trailing period
+fn spelling(token: &Token) -> Option<&str> {
+    if token.is_separator() && !token.value.starts_with(char::is_whitespace) {
+        return Some(&token.value);
+    }
+    if token.possible_types.contains(&TokenType::MonthNameShort) {
+        return Some(if token.value.ends_with('.') { "." } else { "" });
+    }
+    None
+}
+
+/// The spelling most examples holding `resolved` at `pos` use, as the
+/// index of the first example using it and the number of examples
+/// spelling the token some other way. Ties go to the earliest example;
+/// `None` when no example has a spelling there
+pub fn majority_spelling(tokenized_dates: &[Vec<Token>], pos: usize, resolved: TokenType) -> Option<(usize, usize)> {
+    let mut counts: SmallVec<[(&str, usize, usize); 2]> = SmallVec::new();
+    for (i, tokens) in tokenized_dates.iter().enumerate() {
+        let token = &tokens[pos];
+        let Some(key) = spelling(token).filter(|_| token.possible_types.contains(&resolved)) else {
+            continue;
+        };
+        match counts.iter_mut().find(|(k, ..)| *k == key) {
+            Some((_, _, count)) => *count += 1,
+            None => counts.push((key, i, 1)),
+        }
+    }
+    let spelled: usize = counts.iter().map(|&(_, _, count)| count).sum();
+    counts
+        .iter()
+        .max_by_key(|&&(_, first, count)| (count, std::cmp::Reverse(first)))
+        .map(|&(_, first, count)| (first, spelled - count))
+}
+
+/// The tokens to render the format from: the first example's, with each
+/// spelled position taken from an example using the majority spelling
+pub fn spelling_template(tokenized_dates: &[Vec<Token>], resolved: &[TokenType]) -> Vec<Token> {
+    let mut template = tokenized_dates[0].clone();
+    for (pos, token) in template.iter_mut().enumerate() {
+        if let Some((first, _)) = majority_spelling(tokenized_dates, pos, resolved[pos]) {
+            *token = tokenized_dates[first][pos].clone();
+        }
+    }
+    template
+}
+
+/// Check that `tokens` spells each position the way `template` does,
+/// where both have a spelling
+pub fn matches_spelling(tokens: &[Token], template: &[Token]) -> bool {
+    tokens.iter().zip(template).all(|(token, expected)| match (spelling(token), spelling(expected)) {
+        (Some(key), Some(expected)) => key == expected,
+        _ => true,
+    })
+}
+
+/// Candidate token types for one position, before consensus resolves it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionAnalysis {
+    /// Candidate types with the number of examples voting for each,
+    /// most votes first
+    pub candidates: Vec<(TokenType, usize)>,
+    /// Whether the position holds a separator
+    pub is_separator: bool,
+}
+
+/// Collect the raw per-position candidates that consensus starts from
+pub fn analyze_positions(tokenized_dates: &[Vec<Token>]) -> Vec<PositionAnalysis> {
+    let mut position_votes = PositionVotes::new();
+    collect_votes(tokenized_dates, &mut position_votes);
+    analyze_votes(&position_votes)
+}
+
+/// Convert vote counts to per-position candidate lists
+pub fn analyze_votes(position_votes: &[VoteCounter]) -> Vec<PositionAnalysis> {
+    position_votes
+        .iter()
+        .map(|votes| {
+            let mut candidates: Vec<(TokenType, usize)> = votes.iter().collect();
+            // Ties ordered by tag so the output is deterministic
+            candidates.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.tag().cmp(&b.0.tag())));
+            let is_separator = candidates.iter().any(|(t, _)| matches!(t, TokenType::Separator(_)));
+            PositionAnalysis {
+                candidates,
+                is_separator,
+            }
+        })
+        .collect()
+}
+
+/// How consensus resolved one position
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionExplanation {
+    /// Candidate types with the number of examples voting for each,
+    /// most votes first
+    pub candidates: Vec<(TokenType, usize)>,
+    /// The type chosen after consensus and rewrite rules
+    pub resolved: TokenType,
+    /// Share of examples supporting `resolved` (`None` for literal text and
+    /// separators all examples agree on, which don't count toward
+    /// confidence)
+    pub confidence: Option<f64>,
 }
 
 #[derive(Debug, Default, Clone)]
 struct PositionConstraint {
     must_be_day: bool,
+    must_be_year2: bool,
     separator: Option<char>,
 }
 
@@ -377,6 +1021,25 @@ mod tests {
     use super::*;
     use crate::tokenizer::tokenize;
 
+    #[test]
+    fn test_vote_slots_match_vote_types() {
+        for (i, &t) in VOTE_TYPES.iter().enumerate() {
+            assert_eq!(vote_slot(t), Some(i), "{t:?}");
+        }
+        assert_eq!(vote_slot(TokenType::Separator('/')), None);
+
+        let mut votes = VoteCounter::default();
+        for t in [TokenType::Day, TokenType::Separator('/'), TokenType::Day, TokenType::Separator('-')] {
+            votes.add(t);
+        }
+        assert_eq!(votes.count(TokenType::Day), 2);
+        assert!(!votes.contains(TokenType::Month));
+        assert_eq!(votes.count(TokenType::Separator('-')), 1);
+        assert_eq!(votes.iter().count(), 3);
+        votes.clear();
+        assert_eq!(votes.iter().count(), 0);
+    }
+
     #[test]
     fn test_consensus_unambiguous() {
         let dates: Vec<Vec<Token>> = vec![
@@ -384,7 +1047,7 @@ mod tests {
             tokenize("20/04/2025").unwrap(),
         ];
         let options = InferOptions::default();
-        let (resolved, confidence) = resolve_consensus(&dates, &options).unwrap();
+        let (resolved, confidence, _) = resolve_consensus(&dates, &options, &mut PositionVotes::new()).unwrap();
 
         assert_eq!(resolved[0], TokenType::Day);
         assert_eq!(resolved[2], TokenType::Month);
@@ -400,7 +1063,7 @@ mod tests {
             tokenize("15/03/2025").unwrap(),
         ];
         let options = InferOptions::default();
-        let (resolved, _) = resolve_consensus(&dates, &options).unwrap();
+        let (resolved, _, _) = resolve_consensus(&dates, &options, &mut PositionVotes::new()).unwrap();
 
         assert_eq!(resolved[0], TokenType::Day);
         assert_eq!(resolved[2], TokenType::Month);
@@ -416,7 +1079,7 @@ mod tests {
             prefer_dayfirst: true,
             ..Default::default()
         };
-        let (resolved, _) = resolve_consensus(&dates, &options).unwrap();
+        let (resolved, _, _) = resolve_consensus(&dates, &options, &mut PositionVotes::new()).unwrap();
 
         assert_eq!(resolved[0], TokenType::Day);
         assert_eq!(resolved[2], TokenType::Month);
@@ -432,7 +1095,7 @@ mod tests {
             prefer_dayfirst: false,
             ..Default::default()
         };
-        let (resolved, _) = resolve_consensus(&dates, &options).unwrap();
+        let (resolved, _, _) = resolve_consensus(&dates, &options, &mut PositionVotes::new()).unwrap();
 
         assert_eq!(resolved[0], TokenType::Month);
         assert_eq!(resolved[2], TokenType::Day);
@@ -445,10 +1108,49 @@ mod tests {
             tokenize("20 Mar 2025").unwrap(),
         ];
         let options = InferOptions::default();
-        let (resolved, _) = resolve_consensus(&dates, &options).unwrap();
+        let (resolved, _, _) = resolve_consensus(&dates, &options, &mut PositionVotes::new()).unwrap();
 
         assert_eq!(resolved[0], TokenType::Day);
         assert_eq!(resolved[2], TokenType::MonthNameShort);
         assert_eq!(resolved[4], TokenType::Year4);
     }
+
+    #[test]
+    fn test_consensus_weekday_month_collision() {
+        // "May" in the leading slot is read as a mistyped weekday
+        let dates: Vec<Vec<Token>> = vec![
+            tokenize("Mon 15/03/2025").unwrap(),
+            tokenize("May 16/03/2025").unwrap(),
+        ];
+        let options = InferOptions::default();
+        let (resolved, _, _) = resolve_consensus(&dates, &options, &mut PositionVotes::new()).unwrap();
+        assert_eq!(resolved[0], TokenType::WeekdayShort);
+        assert_eq!(resolved[4], TokenType::Month);
+
+        // A month name elsewhere outweighs the majority
+        let dates: Vec<Vec<Token>> = vec![
+            tokenize("Sun 16 Mar 2025").unwrap(),
+            tokenize("Mar 17 Mar 2025").unwrap(),
+            tokenize("Mar 18 Mar 2025").unwrap(),
+        ];
+        let (resolved, _, _) = resolve_consensus(&dates, &options, &mut PositionVotes::new()).unwrap();
+        assert_eq!(resolved[0], TokenType::WeekdayShort);
+        assert_eq!(resolved[4], TokenType::MonthNameShort);
+    }
+
+    #[test]
+    fn test_consensus_trailing_year2_with_day_like_values() {
+        // 24 and 31 could be days, but the last date slot is the year
+        let dates: Vec<Vec<Token>> = vec![
+            tokenize("10/06/24").unwrap(),
+            tokenize("12/07/31").unwrap(),
+            tokenize("01/02/40").unwrap(),
+        ];
+        let options = InferOptions::default();
+        let (resolved, _, _) = resolve_consensus(&dates, &options, &mut PositionVotes::new()).unwrap();
+
+        assert_eq!(resolved[0], TokenType::Day);
+        assert_eq!(resolved[2], TokenType::Month);
+        assert_eq!(resolved[4], TokenType::Year2);
+    }
 }
@@ -1,19 +1,39 @@
 //! Token types and constraint logic for date components
 
 use crate::tokenizer::TypeSet;
+use std::ops::RangeInclusive;
+
+/// Default range of values accepted as 4-digit years
+pub const DEFAULT_YEAR_RANGE: RangeInclusive<u32> = 1900..=2100;
+
+/// Every strptime specifier an inferred format can contain: those of
+/// [`TokenType::strptime_format`], the unpadded `%-` forms emitted with
+/// `detect_padding`, the `%x` / `%X` composites emitted with
+/// `locale_composites`, the `%%` escape for a literal `%`, and the `%?`
+/// placeholder of `UnknownPolicy::Wildcard` (not a strptime specifier)
+pub const SUPPORTED_SPECIFIERS: &[&str] = &[
+    "%Y", "%y", "%m", "%d", "%B", "%b", "%j", "%A", "%a", "%u", "%w", "%G", "%g", "%V", "%H", "%I", "%M", "%S",
+    "%f", "%p", "%z", "%Z", "%-m", "%-d", "%-j", "%-H", "%-I", "%-M", "%-S", "%x", "%X", "%%", "%?",
+];
 
 /// Types of tokens that can appear in a date string
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TokenType {
     // Date components
-    Year4,          // 2025 (4 digits, 1900-2100 range)
-    Year2,          // 25 (2 digits)
-    Month,          // 01-12
-    Day,            // 01-31
-    MonthName,      // January, February, etc.
-    MonthNameShort, // Jan, Feb, etc.
-    WeekdayName,    // Monday, Tuesday, etc.
-    WeekdayShort,   // Mon, Tue, etc.
+    Year4,                   // 2025 (4 digits, 1900-2100 range by default)
+    Year2,                   // 25 (2 digits)
+    Month,                   // 01-12
+    Day,                     // 01-31
+    MonthName,               // January, February, etc.
+    MonthNameShort,          // Jan, Feb, etc.
+    DayOfYear,               // 001-366 (ordinal date)
+    WeekdayName,             // Monday, Tuesday, etc.
+    WeekdayShort,            // Mon, Tue, etc.
+    WeekdayNumber,           // 1-7, Monday = 1 (ISO)
+    WeekdayNumberFromSunday, // 0-6, Sunday = 0
+    IsoYear,                 // 2025 in 2025-W03 (ISO week-numbering year)
+    IsoYear2,                // 25 in 25-W03
+    IsoWeek,                 // 03 in 2025-W03 (01-53)
 
     // Time components
     Hour24,    // 00-23
@@ -22,9 +42,10 @@ pub enum TokenType {
     Second,    // 00-59
     Subsecond, // fractional seconds
     AmPm,      // AM, PM
+    TimeWord,  // noon, midnight (stands in for 12:00 / 00:00)
 
     // Timezone
-    TzOffset, // +05:30, -0800
+    TzOffset, // +05:30, -0800, +05:30:00 (%z accepts seconds)
     TzName,   // UTC, EST, IST
     TzZ,      // Z (UTC indicator)
 
@@ -48,14 +69,21 @@ impl TokenType {
             TokenType::Day => "%d",
             TokenType::MonthName => "%B",
             TokenType::MonthNameShort => "%b",
+            TokenType::DayOfYear => "%j",
             TokenType::WeekdayName => "%A",
             TokenType::WeekdayShort => "%a",
+            TokenType::WeekdayNumber => "%u",
+            TokenType::WeekdayNumberFromSunday => "%w",
+            TokenType::IsoYear => "%G",
+            TokenType::IsoYear2 => "%g",
+            TokenType::IsoWeek => "%V",
             TokenType::Hour24 => "%H",
             TokenType::Hour12 => "%I",
             TokenType::Minute => "%M",
             TokenType::Second => "%S",
             TokenType::Subsecond => "%f",
             TokenType::AmPm => "%p",
+            TokenType::TimeWord => "%H:%M",
             TokenType::TzOffset => "%z",
             TokenType::TzName => "%Z",
             TokenType::TzZ => "Z",
@@ -65,6 +93,77 @@ impl TokenType {
         }
     }
 
+    /// Returns a stable, lowercase tag for this token type.
+    ///
+    /// Separators are tagged `sep:<char>` (e.g. `sep:/`). The tags are part of
+    /// the public API and are what the Python bindings expose.
+    pub fn tag(&self) -> String {
+        let tag = match self {
+            TokenType::Year4 => "year4",
+            TokenType::Year2 => "year2",
+            TokenType::Month => "month",
+            TokenType::Day => "day",
+            TokenType::MonthName => "month_name",
+            TokenType::MonthNameShort => "month_name_short",
+            TokenType::DayOfYear => "day_of_year",
+            TokenType::WeekdayName => "weekday_name",
+            TokenType::WeekdayShort => "weekday_short",
+            TokenType::WeekdayNumber => "weekday_number",
+            TokenType::WeekdayNumberFromSunday => "weekday_number_sunday",
+            TokenType::IsoYear => "iso_year",
+            TokenType::IsoYear2 => "iso_year2",
+            TokenType::IsoWeek => "iso_week",
+            TokenType::Hour24 => "hour24",
+            TokenType::Hour12 => "hour12",
+            TokenType::Minute => "minute",
+            TokenType::Second => "second",
+            TokenType::Subsecond => "subsecond",
+            TokenType::AmPm => "ampm",
+            TokenType::TimeWord => "time_word",
+            TokenType::TzOffset => "tz_offset",
+            TokenType::TzName => "tz_name",
+            TokenType::TzZ => "tz_z",
+            TokenType::Separator(c) => return format!("sep:{}", c),
+            TokenType::DayOrMonth => "day_or_month",
+            TokenType::Unknown => "unknown",
+        };
+        tag.to_string()
+    }
+
+    /// Returns a short prose name for this token type, e.g. `4-digit year`
+    ///
+    /// Separators and unknown tokens have no name and return `""`.
+    pub fn description(&self) -> &'static str {
+        match self {
+            TokenType::Year4 => "4-digit year",
+            TokenType::Year2 => "2-digit year",
+            TokenType::Month => "month",
+            TokenType::Day => "day",
+            TokenType::MonthName => "month name",
+            TokenType::MonthNameShort => "abbreviated month name",
+            TokenType::DayOfYear => "day of year",
+            TokenType::WeekdayName => "weekday name",
+            TokenType::WeekdayShort => "abbreviated weekday name",
+            TokenType::WeekdayNumber => "weekday number (Monday = 1)",
+            TokenType::WeekdayNumberFromSunday => "weekday number (Sunday = 0)",
+            TokenType::IsoYear => "ISO week-numbering year",
+            TokenType::IsoYear2 => "2-digit ISO week-numbering year",
+            TokenType::IsoWeek => "ISO week",
+            TokenType::Hour24 => "hour",
+            TokenType::Hour12 => "12-hour clock hour",
+            TokenType::Minute => "minute",
+            TokenType::Second => "second",
+            TokenType::Subsecond => "fraction of a second",
+            TokenType::AmPm => "AM/PM",
+            TokenType::TimeWord => "noon/midnight",
+            TokenType::TzOffset => "UTC offset",
+            TokenType::TzName => "timezone name",
+            TokenType::TzZ => "Z (UTC)",
+            TokenType::DayOrMonth => "day or month",
+            TokenType::Separator(_) | TokenType::Unknown => "",
+        }
+    }
+
     /// Check if this token type is a date component (not separator/unknown)
     pub fn is_date_component(&self) -> bool {
         !matches!(self, TokenType::Separator(_) | TokenType::Unknown)
@@ -97,37 +196,27 @@ pub const WEEKDAY_NAMES_FULL: [&str; 7] = [
 pub const AMPM: [&str; 4] = ["am", "pm", "a.m.", "p.m."];
 
 /// Determine possible token types for a numeric value
-pub fn possible_types_for_number(value: u32, num_digits: usize) -> TypeSet {
+///
+/// 4-digit values are only considered years when they fall in `year_range`.
+pub fn possible_types_for_number(value: u32, num_digits: usize, year_range: &RangeInclusive<u32>) -> TypeSet {
     let mut types = TypeSet::new();
 
     match num_digits {
         1 | 2 => {
             // Could be day, month, hour, minute, second, or 2-digit year
-            if (1..=12).contains(&value) {
-                types.push(TokenType::DayOrMonth); // Ambiguous day/month
-                types.push(TokenType::Hour12);
-            }
-            if (1..=31).contains(&value) {
-                types.push(TokenType::Day);
-            }
-            if value <= 23 {
-                types.push(TokenType::Hour24);
-            }
-            if value <= 59 {
-                types.push(TokenType::Minute);
-                types.push(TokenType::Second);
-            }
-            if num_digits == 2 && value <= 99 {
-                types.push(TokenType::Year2);
-            }
+            return TypeSet::from_slice(small_number_types(value, num_digits == 2));
         }
-        3 | 6 => {
-            // Likely subsecond component (milliseconds or microseconds)
+        3 | 6 | 9 => {
+            // Likely subsecond component (milliseconds, microseconds or nanoseconds)
             types.push(TokenType::Subsecond);
+            // 3 digits may also be an ordinal day (2025-045)
+            if num_digits == 3 && (1..=366).contains(&value) {
+                types.push(TokenType::DayOfYear);
+            }
         }
         4 => {
             // Likely a year
-            if (1900..=2100).contains(&value) {
+            if year_range.contains(&value) {
                 types.push(TokenType::Year4);
             }
             // Could also be HHMM time without separator
@@ -147,58 +236,233 @@ pub fn possible_types_for_number(value: u32, num_digits: usize) -> TypeSet {
     types
 }
 
-/// Determine token type for a text value
-pub fn token_type_for_text(text: &str) -> TokenType {
-    let lower = text.to_lowercase();
-
-    // Check month names
-    if let Some(idx) = MONTH_NAMES_SHORT.iter().position(|&m| m == lower) {
-        return if text.len() == 3 {
-            TokenType::MonthNameShort
-        } else {
-            // Check if it's a full month name
-            if MONTH_NAMES_FULL.get(idx).is_some_and(|&full| full == lower) {
-                TokenType::MonthName
-            } else {
-                TokenType::MonthNameShort
-            }
-        };
+/// Words naming a time of day, recognized with `allow_time_words`
+pub const TIME_WORDS: [&str; 2] = ["noon", "midnight"];
+
+/// Possible types of a 1-2 digit value, from a fixed table
+///
+/// Each value range maps to exactly one type list, so the tokenizer copies a
+/// static slice instead of testing and pushing each type.
+fn small_number_types(value: u32, two_digits: bool) -> &'static [TokenType] {
+    use TokenType::*;
+
+    // (one-digit, two-digit) lists per range; only two digits can be a year
+    const ZERO: (&[TokenType], &[TokenType]) = (&[Hour24, Minute, Second], &[Hour24, Minute, Second, Year2]);
+    const MONTH: (&[TokenType], &[TokenType]) = (
+        &[DayOrMonth, Hour12, Day, Hour24, Minute, Second],
+        &[DayOrMonth, Hour12, Day, Hour24, Minute, Second, Year2],
+    );
+    const HOUR: &[TokenType] = &[Day, Hour24, Minute, Second, Year2];
+    const DAY: &[TokenType] = &[Day, Minute, Second, Year2];
+    const MINUTE: &[TokenType] = &[Minute, Second, Year2];
+    const YEAR: &[TokenType] = &[Year2];
+
+    match (value, two_digits) {
+        (0, false) => ZERO.0,
+        (0, true) => ZERO.1,
+        (1..=12, false) => MONTH.0,
+        (1..=12, true) => MONTH.1,
+        (13..=23, _) => HOUR,
+        (24..=31, _) => DAY,
+        (32..=59, _) => MINUTE,
+        _ => YEAR,
     }
+}
 
-    if MONTH_NAMES_FULL.iter().any(|&m| m == lower) {
-        return TokenType::MonthName;
-    }
+/// Common timezone abbreviations (case-insensitive matching)
+pub const TZ_NAMES: [&str; 10] = [
+    "utc", "gmt", "est", "pst", "cst", "mst", "ist", "cet", "wet", "eet",
+];
 
-    // Check weekday names
-    if WEEKDAY_NAMES_SHORT.iter().any(|&w| w == lower) {
-        return TokenType::WeekdayShort;
-    }
+/// Determine token type for a text value
+///
+/// All name tables are ASCII, so matching uses `eq_ignore_ascii_case` and
+/// never allocates a lowercased copy of `text`. With `case_sensitive`, month
+/// and weekday names only match in canonical capitalization (`Jan`,
+/// `Monday`); other spellings are `Unknown`.
+pub fn token_type_for_text(text: &str, case_sensitive: bool) -> TokenType {
+    let matches = |name: &&str| name.eq_ignore_ascii_case(text);
+
+    if !case_sensitive || is_capitalized(text) {
+        // Check month names
+        if MONTH_NAMES_SHORT.iter().any(matches) {
+            return TokenType::MonthNameShort;
+        }
+
+        if MONTH_NAMES_FULL.iter().any(matches) {
+            return TokenType::MonthName;
+        }
+
+        // Check weekday names
+        if WEEKDAY_NAMES_SHORT.iter().any(matches) {
+            return TokenType::WeekdayShort;
+        }
 
-    if WEEKDAY_NAMES_FULL.iter().any(|&w| w == lower) {
-        return TokenType::WeekdayName;
+        if WEEKDAY_NAMES_FULL.iter().any(matches) {
+            return TokenType::WeekdayName;
+        }
     }
 
     // Check AM/PM
-    if AMPM.iter().any(|&a| a == lower) {
+    if AMPM.iter().any(matches) {
         return TokenType::AmPm;
     }
 
     // Check timezone indicator
-    if lower == "z" {
+    if text.eq_ignore_ascii_case("z") {
         return TokenType::TzZ;
     }
 
     // Common timezone abbreviations
-    if matches!(lower.as_str(), "utc" | "gmt" | "est" | "pst" | "cst" | "mst" | "ist" | "cet" | "wet" | "eet") {
+    if TZ_NAMES.iter().any(matches) {
         return TokenType::TzName;
     }
 
     TokenType::Unknown
 }
 
+/// Check if a word is capitalized: first letter uppercase, rest lowercase
+fn is_capitalized(text: &str) -> bool {
+    let mut bytes = text.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_uppercase()) && bytes.all(|b| b.is_ascii_lowercase())
+}
+
+/// Check if a word has the shape of a timezone abbreviation (`PDT`, `AEST`)
+///
+/// Only the shape is checked: 2-5 ASCII uppercase letters. Callers must
+/// also require a post-time position, since trailing labels look the same.
+pub fn is_tz_abbreviation(text: &str) -> bool {
+    (2..=5).contains(&text.len()) && text.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+/// Month number (1-12) for a full or short month name
+pub fn month_number(text: &str) -> Option<u32> {
+    let text = text.strip_suffix('.').unwrap_or(text);
+    let matches = |name: &&str| name.eq_ignore_ascii_case(text);
+    MONTH_NAMES_SHORT
+        .iter()
+        .position(matches)
+        .or_else(|| MONTH_NAMES_FULL.iter().position(matches))
+        .map(|i| i as u32 + 1)
+}
+
+/// Check if a value is an ISO 8601 duration (`P1Y2M10DT2H30M`, `PT45S`)
+///
+/// Designators must appear in order (`Y M W D`, then `T` and `H M S`), each
+/// after a number, and at least one must be present. Only the final
+/// component may have a fraction.
+pub fn is_iso_duration(value: &str) -> bool {
+    let Some(rest) = value.trim().strip_prefix(['P', 'p']) else {
+        return false;
+    };
+
+    const DATE: &[u8] = b"YMWD";
+    const TIME: &[u8] = b"HMS";
+    let (mut designators, mut in_time) = (DATE, false);
+    let (mut components, mut digits, mut fraction) = (0, 0, false);
+    let bytes = rest.as_bytes();
+    for (i, &b) in bytes.iter().enumerate() {
+        match b.to_ascii_uppercase() {
+            b'0'..=b'9' => digits += 1,
+            b'.' | b',' if digits > 0 && !fraction => fraction = true,
+            b'T' if !in_time && digits == 0 => {
+                (designators, in_time) = (TIME, true);
+                if i + 1 == bytes.len() {
+                    return false;
+                }
+            }
+            d if digits > 0 => {
+                let Some(idx) = designators.iter().position(|&x| x == d) else {
+                    return false;
+                };
+                if fraction && i + 1 != bytes.len() {
+                    return false;
+                }
+                designators = &designators[idx + 1..];
+                components += 1;
+                digits = 0;
+            }
+            _ => return false,
+        }
+    }
+    components > 0 && digits == 0
+}
+
+/// Season names accepted by [`is_season`] (`fall` is the US `autumn`)
+const SEASONS: &[&str] = &["spring", "summer", "autumn", "fall", "winter"];
+
+/// Check if a value is a season or a quarter with a year, such as
+/// `Spring 2025`, `Q2 2025`, `2025-Q2` or `Winter '24`
+///
+/// Words are case-insensitive; the year is 4 digits, or 2 digits with an
+/// optional apostrophe.
+pub fn is_season(value: &str) -> bool {
+    let parts: Vec<&str> = value.split([' ', '-', '/']).filter(|p| !p.is_empty()).collect();
+    let [a, b] = parts[..] else {
+        return false;
+    };
+    let is_period = |p: &str| {
+        SEASONS.iter().any(|s| s.eq_ignore_ascii_case(p))
+            || matches!(p.as_bytes(), [b'Q' | b'q', b'1'..=b'4'])
+    };
+    let is_year = |p: &str| match p.strip_prefix('\'') {
+        Some(digits) => digits.len() == 2 && digits.bytes().all(|b| b.is_ascii_digit()),
+        None => matches!(p.len(), 2 | 4) && p.bytes().all(|b| b.is_ascii_digit()),
+    };
+    (is_period(a) && is_year(b)) || (is_year(a) && is_period(b))
+}
+
+/// Check if a value is a Unix timestamp in seconds (9-10 digits) or
+/// milliseconds (13 digits)
+///
+/// Underscores are read as digit-group separators only in thousands
+/// grouping (`1_705_312_200`), so `2025_01_15` is not an epoch.
+/// Scientific notation (`1.7053122e9`) is accepted in the same ranges.
+pub fn is_epoch(value: &str) -> bool {
+    let value = value.trim();
+    if value.contains(['e', 'E']) {
+        let Ok(v) = value.parse::<f64>() else {
+            return false;
+        };
+        return (1e8..1e10).contains(&v) || (1e12..1e13).contains(&v);
+    }
+
+    let mut groups = value.split('_');
+    let first = groups.next().unwrap_or_default();
+    let grouped = value.contains('_');
+    if first.is_empty() || (grouped && first.len() > 3) || !first.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    let mut digits = first.len();
+    for group in groups {
+        if group.len() != 3 || !group.bytes().all(|b| b.is_ascii_digit()) {
+            return false;
+        }
+        digits += 3;
+    }
+    matches!(digits, 9 | 10 | 13)
+}
+
+/// Number of days in `month`; February has 29 days when `year` is unknown
+pub fn days_in_month(month: u32, year: Option<u32>) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 => match year {
+            Some(y) if !(y % 4 == 0 && (y % 100 != 0 || y % 400 == 0)) => 28,
+            _ => 29,
+        },
+        _ => 31,
+    }
+}
+
 /// Check if a character is a common date/time separator
 pub fn is_separator(c: char) -> bool {
-    matches!(c, '/' | '-' | '.' | ':' | ' ' | 'T' | ',' | '_')
+    matches!(c, '/' | '-' | '.' | ':' | ' ' | 'T' | ',' | '_') || is_cjk_marker(c)
+}
+
+/// Check if a character is a CJK year/month/day marker (`2025年01月15日`)
+pub fn is_cjk_marker(c: char) -> bool {
+    matches!(c, '年' | '月' | '日')
 }
 
 #[cfg(test)]
@@ -208,25 +472,191 @@ mod tests {
     #[test]
     fn test_number_constraints() {
         // Value 15 can only be day (not month)
-        let types = possible_types_for_number(15, 2);
+        let types = possible_types_for_number(15, 2, &DEFAULT_YEAR_RANGE);
         assert!(types.contains(&TokenType::Day));
         assert!(!types.contains(&TokenType::DayOrMonth));
 
         // Value 5 is ambiguous
-        let types = possible_types_for_number(5, 2);
+        let types = possible_types_for_number(5, 2, &DEFAULT_YEAR_RANGE);
         assert!(types.contains(&TokenType::DayOrMonth));
     }
 
+    #[test]
+    fn test_hour_twelve_is_valid_for_both_clocks() {
+        let types = possible_types_for_number(12, 2, &DEFAULT_YEAR_RANGE);
+        assert!(types.contains(&TokenType::Hour12));
+        assert!(types.contains(&TokenType::Hour24));
+    }
+
+    #[test]
+    fn test_supported_specifiers_cover_token_types() {
+        use TokenType::*;
+        let all = [
+            Year4, Year2, Month, Day, MonthName, MonthNameShort, DayOfYear, WeekdayName, WeekdayShort,
+            WeekdayNumber, WeekdayNumberFromSunday, IsoYear, IsoYear2, IsoWeek, Hour24, Hour12, Minute, Second,
+            Subsecond, AmPm, TimeWord, TzOffset, TzName, TzZ, Separator('/'), DayOrMonth, Unknown,
+        ];
+        for token_type in all {
+            // Fails to compile when a variant is added, so `all` stays complete
+            match token_type {
+                Year4 | Year2 | Month | Day | MonthName | MonthNameShort | DayOfYear | WeekdayName | WeekdayShort
+                | WeekdayNumber | WeekdayNumberFromSunday | IsoYear | IsoYear2 | IsoWeek | Hour24 | Hour12
+                | Minute | Second | Subsecond | AmPm | TimeWord | TzOffset | TzName | TzZ | Separator(_)
+                | DayOrMonth | Unknown => {}
+            }
+            // TimeWord stands in for "%H:%M"; TzZ is the literal "Z"
+            for spec in token_type.strptime_format().split(':').filter(|s| s.starts_with('%')) {
+                assert!(SUPPORTED_SPECIFIERS.contains(&spec), "{spec} missing for {token_type:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_tags() {
+        assert_eq!(TokenType::Day.tag(), "day");
+        assert_eq!(TokenType::Year4.tag(), "year4");
+        assert_eq!(TokenType::MonthNameShort.tag(), "month_name_short");
+        assert_eq!(TokenType::TzOffset.tag(), "tz_offset");
+        assert_eq!(TokenType::Separator('/').tag(), "sep:/");
+        assert_eq!(TokenType::Separator(' ').tag(), "sep: ");
+    }
+
     #[test]
     fn test_month_name_detection() {
-        assert_eq!(token_type_for_text("Jan"), TokenType::MonthNameShort);
-        assert_eq!(token_type_for_text("January"), TokenType::MonthName);
-        assert_eq!(token_type_for_text("JAN"), TokenType::MonthNameShort);
+        assert_eq!(token_type_for_text("Jan", false), TokenType::MonthNameShort);
+        assert_eq!(token_type_for_text("January", false), TokenType::MonthName);
+        assert_eq!(token_type_for_text("JAN", false), TokenType::MonthNameShort);
+        assert_eq!(token_type_for_text("May", false), TokenType::MonthNameShort);
+        assert_eq!(token_type_for_text("MAY", false), TokenType::MonthNameShort);
+    }
+
+    #[test]
+    fn test_case_sensitive_names() {
+        assert_eq!(token_type_for_text("Jan", true), TokenType::MonthNameShort);
+        assert_eq!(token_type_for_text("January", true), TokenType::MonthName);
+        assert_eq!(token_type_for_text("Monday", true), TokenType::WeekdayName);
+        assert_eq!(token_type_for_text("jan", true), TokenType::Unknown);
+        assert_eq!(token_type_for_text("JAN", true), TokenType::Unknown);
+        assert_eq!(token_type_for_text("january", true), TokenType::Unknown);
+        // AM/PM and timezones are unaffected
+        assert_eq!(token_type_for_text("pm", true), TokenType::AmPm);
+        assert_eq!(token_type_for_text("UTC", true), TokenType::TzName);
+    }
+
+    #[test]
+    fn test_text_classification_ignores_case() {
+        assert_eq!(token_type_for_text("tHuRsDaY", false), TokenType::WeekdayName);
+        assert_eq!(token_type_for_text("pm", false), TokenType::AmPm);
+        assert_eq!(token_type_for_text("Z", false), TokenType::TzZ);
+        assert_eq!(token_type_for_text("Est", false), TokenType::TzName);
+        assert_eq!(token_type_for_text("Janu", false), TokenType::Unknown);
+        assert_eq!(token_type_for_text("Jän", false), TokenType::Unknown);
+    }
+
+    #[test]
+    fn test_subsecond_widths() {
+        for digits in [6, 9] {
+            let types = possible_types_for_number(123, digits, &DEFAULT_YEAR_RANGE);
+            assert_eq!(types.as_slice(), &[TokenType::Subsecond]);
+        }
+        let types = possible_types_for_number(123, 3, &DEFAULT_YEAR_RANGE);
+        assert_eq!(types.as_slice(), &[TokenType::Subsecond, TokenType::DayOfYear]);
+        let types = possible_types_for_number(999, 3, &DEFAULT_YEAR_RANGE);
+        assert_eq!(types.as_slice(), &[TokenType::Subsecond]);
+    }
+
+    #[test]
+    fn test_tz_abbreviation_shape() {
+        assert!(is_tz_abbreviation("PDT"));
+        assert!(is_tz_abbreviation("AEST"));
+        assert!(!is_tz_abbreviation("Pdt"));
+        assert!(!is_tz_abbreviation("X"));
+        assert!(!is_tz_abbreviation("ABCDEF"));
     }
 
     #[test]
     fn test_year_detection() {
-        let types = possible_types_for_number(2025, 4);
+        let types = possible_types_for_number(2025, 4, &DEFAULT_YEAR_RANGE);
         assert!(types.contains(&TokenType::Year4));
     }
+
+    #[test]
+    fn test_year_range() {
+        let types = possible_types_for_number(1776, 4, &DEFAULT_YEAR_RANGE);
+        assert!(!types.contains(&TokenType::Year4));
+        let types = possible_types_for_number(1776, 4, &(1700..=2100));
+        assert!(types.contains(&TokenType::Year4));
+    }
+
+    #[test]
+    fn test_is_iso_duration() {
+        for value in ["P1Y2M10DT2H30M", "PT45S", "P3W", "PT0.5S", "P1DT12H", "p1y"] {
+            assert!(is_iso_duration(value), "{value}");
+        }
+        for value in ["P", "PT", "P1", "P1M1Y", "PT1D", "P1.5Y2M", "Pending", "2025-01-15"] {
+            assert!(!is_iso_duration(value), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_is_epoch() {
+        for value in ["1705312200", "1_705_312_200", "1705312200123", "1_705_312_200_123", "1.7053122e9", " 999999999 "] {
+            assert!(is_epoch(value), "{value}");
+        }
+        for value in ["20250115", "2025_01_15", "17_05312200", "1705_312_200", "_705_312_200", "1.7e3", "170531220012"] {
+            assert!(!is_epoch(value), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_is_season() {
+        for value in ["Spring 2025", "fall 2024", "WINTER '24", "Q2 2025", "2025-Q2", "q4/2025", "2025 Autumn"] {
+            assert!(is_season(value), "{value}");
+        }
+        for value in ["Spring", "Q5 2025", "Q2 2025 10:30", "March 2025", "Spring 202", "Q2 '2025", "2025-01"] {
+            assert!(!is_season(value), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(days_in_month(1, Some(2025)), 31);
+        assert_eq!(days_in_month(4, None), 30);
+        assert_eq!(days_in_month(2, Some(2025)), 28);
+        assert_eq!(days_in_month(2, Some(2024)), 29);
+        assert_eq!(days_in_month(2, Some(1900)), 28);
+        assert_eq!(days_in_month(2, Some(2000)), 29);
+        assert_eq!(days_in_month(2, None), 29);
+        assert_eq!(month_number("Sep"), Some(9));
+        assert_eq!(month_number("DECEMBER"), Some(12));
+        assert_eq!(month_number("Foo"), None);
+    }
+
+    #[test]
+    fn test_small_number_table_matches_ranges() {
+        // Reference: the per-range checks the table replaces
+        for num_digits in [1, 2] {
+            for value in 0..10u32.pow(num_digits as u32) {
+                let mut expected = Vec::new();
+                if (1..=12).contains(&value) {
+                    expected.extend([TokenType::DayOrMonth, TokenType::Hour12]);
+                }
+                if (1..=31).contains(&value) {
+                    expected.push(TokenType::Day);
+                }
+                if value <= 23 {
+                    expected.push(TokenType::Hour24);
+                }
+                if value <= 59 {
+                    expected.extend([TokenType::Minute, TokenType::Second]);
+                }
+                if num_digits == 2 {
+                    expected.push(TokenType::Year2);
+                }
+                let types = possible_types_for_number(value, num_digits, &DEFAULT_YEAR_RANGE);
+                assert_eq!(types.as_slice(), expected.as_slice(), "{value} with {num_digits} digits");
+                assert!(!types.spilled());
+            }
+        }
+    }
 }
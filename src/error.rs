@@ -1,7 +1,11 @@
 //! Error types for dateinfer-rs
 
+use std::collections::BTreeMap;
+
 use thiserror::Error;
 
+use crate::constraints::TokenType;
+
 /// Result type alias for dateinfer operations
 pub type Result<T> = std::result::Result<T, DateInferError>;
 
@@ -13,8 +17,28 @@ pub enum DateInferError {
     EmptyInput,
 
     /// Date strings have inconsistent token structures
-    #[error("date strings have inconsistent formats")]
-    InconsistentFormats,
+    #[error("date strings have inconsistent formats (examples per token count: {length_counts:?})")]
+    InconsistentFormats {
+        /// Number of (sampled) examples with each token count
+        length_counts: BTreeMap<usize, usize>,
+    },
+
+    /// None of the inputs produced a date component (all empty or symbols)
+    #[error("none of the {input_count} inputs contain date components")]
+    NoDateTokens { input_count: usize },
+
+    /// Most inputs are ISO 8601 durations (`P1Y2M10DT2H30M`), not dates
+    #[error("{duration_count} of {total_count} inputs look like ISO 8601 durations, not dates")]
+    LooksLikeDuration { duration_count: usize, total_count: usize },
+
+    /// Most inputs are Unix timestamps (`1705312200`), not date strings
+    #[error("{epoch_count} of {total_count} inputs look like Unix timestamps, not dates")]
+    LooksLikeEpoch { epoch_count: usize, total_count: usize },
+
+    /// Most inputs are seasons or quarters (`Spring 2025`, `Q2 2025`), which
+    /// no strptime format can express
+    #[error("{season_count} of {total_count} inputs look like seasons or quarters, not dates")]
+    LooksLikeSeason { season_count: usize, total_count: usize },
 
     /// Could not parse a date string
     #[error("failed to tokenize date string: {0}")]
@@ -28,11 +52,31 @@ pub enum DateInferError {
     #[error("could not resolve ambiguous date components")]
     UnresolvableAmbiguity,
 
+    /// A pinned position is out of range or incompatible with the examples
+    #[error("pinned position {index} cannot be {token_type:?}")]
+    InvalidPin { index: usize, token_type: TokenType },
+
+    /// With `assume_iso`, an example isn't in year-month-day order
+    #[error("'{example}' is not a year-month-day date")]
+    NotIsoOrder { example: String },
+
     /// No valid date pattern found
     #[error("no valid date pattern found in input")]
     NoValidPattern,
 
+    /// With `require_date` / `require_time`, the format lacks that part
+    #[error("inferred format '{format}' has no {component} component")]
+    MissingComponent { component: &'static str, format: String },
+
     /// Strict validation failed: some dates are incompatible with the inferred format
     #[error("strict validation failed: {failed_count} of {total_count} dates incompatible")]
     StrictValidationFailed { failed_count: usize, total_count: usize },
+
+    /// With `verify`, an example doesn't parse with the inferred format
+    #[error("'{example}' does not parse with the inferred format '{format}'")]
+    VerificationFailed { example: String, format: String },
+
+    /// Reading line-delimited input failed
+    #[error("failed to read input: {0}")]
+    Io(#[from] std::io::Error),
 }
@@ -1,24 +1,61 @@
 //! Generate strptime format strings from resolved tokens
 
-use crate::constraints::TokenType;
+use crate::constraints::{TokenType, AMPM, MONTH_NAMES_FULL, MONTH_NAMES_SHORT, WEEKDAY_NAMES_FULL, WEEKDAY_NAMES_SHORT};
 use crate::tokenizer::Token;
 
 /// Convert resolved tokens to a strptime format string
+///
+/// Literal runs (separators and unknown tokens) are escaped so that a `%`
+/// in the input becomes `%%`. The output is valid for both strptime and
+/// chrono's `format::strftime`, which share the `%%` escape.
 pub fn to_strptime(tokens: &[Token], resolved_types: &[TokenType]) -> String {
+    to_strptime_with_padding(tokens, resolved_types, &[], UnknownPolicy::Literal)
+}
+
+/// Convert resolved tokens to a strptime format string, emitting the
+/// unpadded form (`%-d`, `%-j`, ...) at positions flagged in `unpadded`
+/// and unknown tokens as `unknown` directs
+pub fn to_strptime_with_padding(
+    tokens: &[Token],
+    resolved_types: &[TokenType],
+    unpadded: &[bool],
+    unknown: UnknownPolicy,
+) -> String {
     let mut format = String::new();
 
-    for (token, token_type) in tokens.iter().zip(resolved_types.iter()) {
+    for (pos, (token, token_type)) in tokens.iter().zip(resolved_types.iter()).enumerate() {
         match token_type {
             TokenType::Separator(c) => {
-                // Escape special characters in strptime
-                match c {
-                    '%' => format.push_str("%%"),
-                    _ => format.push(*c),
+                // A doubled separator ("//") is one token
+                let repeat = if token.value.chars().all(|v| v == *c) { token.value.chars().count() } else { 1 };
+                for _ in 0..repeat.max(1) {
+                    push_literal_char(&mut format, *c);
                 }
             }
+            TokenType::Unknown if unknown == UnknownPolicy::Wildcard => {
+                format.push_str("%?");
+            }
             TokenType::Unknown => {
                 // Keep original value as literal
-                format.push_str(&token.value);
+                for c in token.value.chars() {
+                    push_literal_char(&mut format, c);
+                }
+            }
+            TokenType::MonthName | TokenType::MonthNameShort if token.value.ends_with('.') => {
+                // "Jan." keeps its period
+                format.push_str(token_type.strptime_format());
+                format.push('.');
+            }
+            TokenType::TzOffset => {
+                // Keep a GMT / UTC prefix attached to the offset as a literal
+                for c in token.value.chars().take_while(|c| c.is_ascii_alphabetic()) {
+                    push_literal_char(&mut format, c);
+                }
+                format.push_str("%z");
+            }
+            _ if unpadded.get(pos).copied().unwrap_or(false) => {
+                format.push_str("%-");
+                format.push_str(&token_type.strptime_format()[1..]);
             }
             _ => {
                 format.push_str(token_type.strptime_format());
@@ -29,6 +66,246 @@ pub fn to_strptime(tokens: &[Token], resolved_types: &[TokenType]) -> String {
     format
 }
 
+/// Flag numeric positions where some example is narrower than the
+/// zero-padded width (`5` for `%d`, `45` for `%j`)
+pub fn unpadded_positions(tokenized: &[Vec<Token>], resolved_types: &[TokenType]) -> Vec<bool> {
+    resolved_types
+        .iter()
+        .enumerate()
+        .map(|(pos, token_type)| {
+            let width = match token_type {
+                TokenType::DayOfYear => 3,
+                TokenType::Day
+                | TokenType::Month
+                | TokenType::Hour24
+                | TokenType::Hour12
+                | TokenType::Minute
+                | TokenType::Second => 2,
+                _ => return false,
+            };
+            tokenized.iter().any(|tokens| tokens[pos].value.len() < width)
+        })
+        .collect()
+}
+
+/// Replace the single-character separators (`/`, `.`, `-` or a space)
+/// between date specifiers (`%Y`, `%m`, `%d`, `%b`, ...) with `separator`
+///
+/// Separators touching a time, timezone or literal component are left as
+/// they are, so `%d.%m.%Y %H:%M` becomes `%d-%m-%Y %H:%M` for `'-'`, as
+/// are other characters such as the CJK markers in `%Y年%m月%d日`.
+pub fn canonicalize(format: &str, separator: char) -> String {
+    enum Item<'a> {
+        Spec(&'a str),
+        Lit(char),
+    }
+
+    let mut items = Vec::new();
+    let mut rest = format;
+    while let Some(c) = rest.chars().next() {
+        if c == '%' {
+            let width = match rest[1..].chars().next() {
+                Some('%') => {
+                    items.push(Item::Lit('%'));
+                    rest = &rest[2..];
+                    continue;
+                }
+                Some('-') => 3,
+                Some(_) => 2,
+                None => 1,
+            };
+            let width = rest.char_indices().nth(width).map_or(rest.len(), |(i, _)| i);
+            items.push(Item::Spec(&rest[..width]));
+            rest = &rest[width..];
+        } else {
+            items.push(Item::Lit(c));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    let is_date = |item: Option<&Item>| {
+        matches!(item, Some(Item::Spec(spec)) if matches!(
+            spec.trim_start_matches(['%', '-']),
+            "Y" | "y" | "m" | "d" | "b" | "B" | "j"
+        ))
+    };
+
+    let mut out = String::with_capacity(format.len());
+    for (i, item) in items.iter().enumerate() {
+        match item {
+            Item::Spec(spec) => out.push_str(spec),
+            Item::Lit('/' | '.' | '-' | ' ') if i > 0 && is_date(items.get(i - 1)) && is_date(items.get(i + 1)) => {
+                push_literal_char(&mut out, separator);
+            }
+            Item::Lit(c) => push_literal_char(&mut out, *c),
+        }
+    }
+    out
+}
+
+/// Replace explicit runs matching the C locale's composite specifiers:
+/// `%m/%d/%y` becomes `%x` and `%H:%M:%S` becomes `%X`
+///
+/// `%c` is never produced: the C locale spells it `%a %b %e %H:%M:%S %Y`,
+/// with a space-padded day that inference doesn't emit.
+pub fn collapse_locale_composites(format: &str) -> String {
+    const COMPOSITES: [(&str, &str); 2] = [("%m/%d/%y", "%x"), ("%H:%M:%S", "%X")];
+
+    let mut out = String::with_capacity(format.len());
+    let mut rest = format;
+    while let Some(c) = rest.chars().next() {
+        if let Some(&(long, short)) = COMPOSITES.iter().find(|(long, _)| rest.starts_with(long)) {
+            out.push_str(short);
+            rest = &rest[long.len()..];
+        } else if rest.starts_with("%%") {
+            out.push_str("%%");
+            rest = &rest[2..];
+        } else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    out
+}
+
+/// How [`TokenType::Unknown`] positions appear in the inferred format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnknownPolicy {
+    /// The first example's text, as a literal. Rows with other text at
+    /// the position lower the confidence
+    #[default]
+    Literal,
+    /// `%?`, a placeholder for any run of text. It is not a strptime
+    /// specifier: use the format through [`crate::InferResult::to_regex`],
+    /// which matches it as `(.+?)`
+    Wildcard,
+    /// Fail with [`crate::DateInferError::NoValidPattern`]
+    Error,
+}
+
+/// Platform dialect for rendering a format string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatFlavor {
+    /// glibc / chrono: unpadded fields as `%-d` (the inferred form)
+    Posix,
+    /// Python's `datetime.strptime`, which has no `%-d` but whose `%d`
+    /// already accepts unpadded values
+    PythonStrptime,
+    /// MSVC `strftime`, which spells unpadded fields `%#d`
+    WindowsStrftime,
+}
+
+/// Rewrite the unpadded specifiers of `format` for `flavor`
+///
+/// Other specifiers, timezones included, are spelled the same in every
+/// flavor and pass through unchanged.
+pub fn to_flavor(format: &str, flavor: FormatFlavor) -> String {
+    let unpadded = match flavor {
+        FormatFlavor::Posix => return format.to_string(),
+        FormatFlavor::PythonStrptime => "%",
+        FormatFlavor::WindowsStrftime => "%#",
+    };
+
+    let mut out = String::with_capacity(format.len());
+    let mut rest = format;
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("%%") {
+            out.push_str("%%");
+            rest = &rest[2..];
+        } else if rest.starts_with("%-") {
+            out.push_str(unpadded);
+            rest = &rest[2..];
+        } else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    out
+}
+
+/// Translate a strptime format into an anchored regex with one capture
+/// group per specifier
+///
+/// Numbers match any width up to the specifier's (`%d` accepts `5` and
+/// `05`), names match the tokenizer's name tables case-insensitively, and
+/// literal characters are escaped.
+pub fn to_regex(format: &str) -> String {
+    let mut out = String::from("^");
+    let mut rest = format;
+    while let Some(c) = rest.chars().next() {
+        if c != '%' {
+            push_regex_literal(&mut out, c);
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+        // Unpadded %-d matches like %d
+        let spec_start = if rest[1..].starts_with('-') { 2 } else { 1 };
+        let Some(spec) = rest[spec_start..].chars().next() else {
+            push_regex_literal(&mut out, '%');
+            break;
+        };
+        rest = &rest[spec_start + spec.len_utf8()..];
+        let pattern = match spec {
+            'Y' | 'G' => r"(\d{4})".to_string(),
+            'y' | 'g' => r"(\d{2})".to_string(),
+            'm' | 'd' | 'H' | 'I' | 'M' | 'S' | 'V' => r"(\d{1,2})".to_string(),
+            'j' => r"(\d{1,3})".to_string(),
+            'u' | 'w' => r"(\d)".to_string(),
+            'f' => r"(\d{1,9})".to_string(),
+            'B' => names_regex(&MONTH_NAMES_FULL),
+            'b' => names_regex(&MONTH_NAMES_SHORT),
+            'A' => names_regex(&WEEKDAY_NAMES_FULL),
+            'a' => names_regex(&WEEKDAY_NAMES_SHORT),
+            'p' => names_regex(&AMPM),
+            'z' => r"([+-]\d{2}(?::?\d{2}(?::?\d{2})?)?)".to_string(),
+            'Z' => r"([A-Za-z]+)".to_string(),
+            '?' => r"(.+?)".to_string(),
+            'x' => to_regex("%m/%d/%y").trim_matches(['^', '$']).to_string(),
+            'X' => to_regex("%H:%M:%S").trim_matches(['^', '$']).to_string(),
+            '%' => r"%".to_string(),
+            other => {
+                let mut literal = String::new();
+                push_regex_literal(&mut literal, '%');
+                push_regex_literal(&mut literal, other);
+                literal
+            }
+        };
+        out.push_str(&pattern);
+    }
+    out.push('$');
+    out
+}
+
+/// A case-insensitive capture group matching any of `names`
+fn names_regex(names: &[&str]) -> String {
+    let mut alternatives = Vec::with_capacity(names.len());
+    for name in names {
+        let mut escaped = String::new();
+        for c in name.chars() {
+            push_regex_literal(&mut escaped, c);
+        }
+        alternatives.push(escaped);
+    }
+    format!("((?i:{}))", alternatives.join("|"))
+}
+
+/// Append a literal character, backslash-escaping regex metacharacters
+fn push_regex_literal(out: &mut String, c: char) {
+    if matches!(c, '\\' | '.' | '+' | '*' | '?' | '(' | ')' | '|' | '[' | ']' | '{' | '}' | '^' | '$' | '#' | '&' | '-' | '~') {
+        out.push('\\');
+    }
+    out.push(c);
+}
+
+/// Append a literal character, escaping `%` as `%%`
+fn push_literal_char(format: &mut String, c: char) {
+    if c == '%' {
+        format.push_str("%%");
+    } else {
+        format.push(c);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,4 +368,117 @@ mod tests {
         ];
         assert_eq!(to_strptime(&tokens, &resolved), "%Y-%m-%d %H:%M:%S");
     }
+
+    #[test]
+    fn test_strptime_tz_name_and_offset() {
+        let tokens = tokenize("09:52:52 EST -0500").unwrap();
+        let resolved = vec![
+            TokenType::Hour24,
+            TokenType::Separator(':'),
+            TokenType::Minute,
+            TokenType::Separator(':'),
+            TokenType::Second,
+            TokenType::Separator(' '),
+            TokenType::TzName,
+            TokenType::Separator(' '),
+            TokenType::TzOffset,
+        ];
+        assert_eq!(to_strptime(&tokens, &resolved), "%H:%M:%S %Z %z");
+    }
+
+    #[test]
+    fn test_strptime_escapes_percent_in_literals() {
+        let mut tokens = tokenize("15/03/2025 x").unwrap();
+        let last = tokens.last_mut().unwrap();
+        last.value = "50%".to_string();
+        let resolved = vec![
+            TokenType::Day,
+            TokenType::Separator('/'),
+            TokenType::Month,
+            TokenType::Separator('/'),
+            TokenType::Year4,
+            TokenType::Separator(' '),
+            TokenType::Unknown,
+        ];
+        assert_eq!(to_strptime(&tokens, &resolved), "%d/%m/%Y 50%%");
+    }
+
+    #[test]
+    fn test_strptime_escapes_percent_separator() {
+        let tokens = tokenize("15/03/2025").unwrap();
+        let resolved = vec![
+            TokenType::Day,
+            TokenType::Separator('%'),
+            TokenType::Month,
+            TokenType::Separator('%'),
+            TokenType::Year4,
+        ];
+        assert_eq!(to_strptime(&tokens, &resolved), "%d%%%m%%%Y");
+    }
+
+    #[test]
+    fn test_strptime_unpadded() {
+        let tokenized = vec![tokenize("5/1/2024").unwrap(), tokenize("12/15/2024").unwrap()];
+        let resolved = vec![
+            TokenType::Month,
+            TokenType::Separator('/'),
+            TokenType::Day,
+            TokenType::Separator('/'),
+            TokenType::Year4,
+        ];
+        let unpadded = unpadded_positions(&tokenized, &resolved);
+        assert_eq!(unpadded, [true, false, true, false, false]);
+        assert_eq!(to_strptime_with_padding(&tokenized[0], &resolved, &unpadded, UnknownPolicy::Literal), "%-m/%-d/%Y");
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        assert_eq!(canonicalize("%d.%m.%Y", '-'), "%d-%m-%Y");
+        assert_eq!(canonicalize("%Y/%m/%d %H:%M:%S", '-'), "%Y-%m-%d %H:%M:%S");
+        assert_eq!(canonicalize("%-d/%-m/%y", '.'), "%-d.%-m.%y");
+        assert_eq!(canonicalize("%b %d, %Y", '-'), "%b-%d, %Y");
+        assert_eq!(canonicalize("%Y-%m-%dT%H:%M:%SZ", '/'), "%Y/%m/%dT%H:%M:%SZ");
+        assert_eq!(canonicalize("%d/%m/%Y", '%'), "%d%%%m%%%Y");
+        assert_eq!(canonicalize("%Y年%m月%d日", '-'), "%Y年%m月%d日");
+    }
+
+    #[test]
+    fn test_to_flavor() {
+        let format = "%-d/%-m/%Y %H:%M %z";
+        assert_eq!(to_flavor(format, FormatFlavor::Posix), format);
+        assert_eq!(to_flavor(format, FormatFlavor::PythonStrptime), "%d/%m/%Y %H:%M %z");
+        assert_eq!(to_flavor(format, FormatFlavor::WindowsStrftime), "%#d/%#m/%Y %H:%M %z");
+        assert_eq!(to_flavor("%%-d %-j", FormatFlavor::WindowsStrftime), "%%-d %#j");
+    }
+
+    #[test]
+    fn test_collapse_locale_composites() {
+        assert_eq!(collapse_locale_composites("%m/%d/%y"), "%x");
+        assert_eq!(collapse_locale_composites("%m/%d/%y %H:%M:%S"), "%x %X");
+        assert_eq!(collapse_locale_composites("%Y-%m-%dT%H:%M:%S.%f"), "%Y-%m-%dT%X.%f");
+        assert_eq!(collapse_locale_composites("%m/%d/%Y %H:%M"), "%m/%d/%Y %H:%M");
+        assert_eq!(collapse_locale_composites("%%m/%d/%y"), "%%m/%d/%y");
+    }
+
+    #[test]
+    fn test_to_regex() {
+        use regex::Regex;
+
+        assert_eq!(to_regex("%d/%m/%Y"), r"^(\d{1,2})/(\d{1,2})/(\d{4})$");
+
+        let re = Regex::new(&to_regex("%a, %d %b %Y %H:%M:%S.%f %z")).unwrap();
+        let caps = re.captures("Mon, 13 jan 2014 09:52:52.123 +05:30").unwrap();
+        assert_eq!(&caps[3], "jan");
+        assert_eq!(&caps[9], "+05:30");
+        assert!(!re.is_match("Mon, 13 Foo 2014 09:52:52.123 +05:30"));
+        assert!(!re.is_match("Mon, 13 Jan 2014 09:52:52 +05:30"));
+
+        // Literals are escaped, unpadded and composite specifiers expand
+        let re = Regex::new(&to_regex("%-d.%-m.%Y (%p) 50%%")).unwrap();
+        assert!(re.is_match("5.3.2025 (p.m.) 50%"));
+        assert!(!re.is_match("5x3x2025 (pm) 50%"));
+        let re = Regex::new(&to_regex("%x %X")).unwrap();
+        assert!(re.is_match("03/15/25 10:30:00"));
+        assert!(!re.is_match("03/15/2025 10:30:00"));
+    }
 }
@@ -24,18 +24,30 @@ mod consensus;
 mod error;
 mod format;
 mod prescan;
+mod reader;
 mod rules;
 mod tokenizer;
 
-pub use constraints::TokenType;
+pub use consensus::{PositionAnalysis, PositionExplanation};
+pub use constraints::{TokenType, DEFAULT_YEAR_RANGE, SUPPORTED_SPECIFIERS};
 pub use error::{DateInferError, Result};
-pub use tokenizer::Token;
+pub use format::{FormatFlavor, UnknownPolicy};
+pub use reader::infer_from_reader;
+pub use tokenizer::{tokenize, tokenize_with_options, Token, TypeSet};
 
-use consensus::resolve_consensus;
-use format::to_strptime;
+use constraints::is_cjk_marker;
+use tokenizer::tokenize_into;
+
+use consensus::{
+    analyze_positions, analyze_votes, matches_spelling, resolve_consensus, resolve_consensus_by_position,
+    spelling_template, PositionVotes,
+};
+use format::{collapse_locale_composites, to_strptime, to_strptime_with_padding, unpadded_positions};
 use rules::apply_rules;
-use rustc_hash::FxHashMap;
-use tokenizer::tokenize;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Configuration options for inference
 #[derive(Debug, Clone)]
@@ -46,6 +58,174 @@ pub struct InferOptions {
     pub min_confidence: f64,
     /// Fail if any example doesn't match the inferred format (default: false)
     pub strict: bool,
+    /// Skip null markers (see [`DEFAULT_NULL_VALUES`]) instead of treating
+    /// them as unparseable rows (default: false)
+    pub skip_nulls: bool,
+    /// Source-specific null markers such as `--` or `???`, skipped and
+    /// counted in [`InferResult::skipped_count`] whether or not
+    /// `skip_nulls` is set. Matched like the defaults: case-insensitively,
+    /// ignoring surrounding whitespace (default: empty)
+    pub null_values: Vec<String>,
+    /// 4-digit values accepted as years (default: 1900..=2100)
+    pub year_range: RangeInclusive<u32>,
+    /// Only match month and weekday names in canonical capitalization,
+    /// e.g. `Jan` but not `jan` or `JAN` (default: false)
+    pub case_sensitive: bool,
+    /// Accept a mix of `HH:MM` and `HH:MM:SS` rows as one column whose
+    /// seconds are optional (default: false)
+    pub optional_seconds: bool,
+    /// Accept a mix of rows with and without a trailing timezone (`Z`,
+    /// `+05:30`, ` UTC`) as one column whose timezone is optional; the
+    /// minority shape is reported in [`InferResult::alternate_format`].
+    /// The format takes the timezone most rows have, with `Z` counted as
+    /// an offset (Python's `%z` reads `Z`; chrono's needs `+00:00`); rows
+    /// with another kind lower the confidence (default: false)
+    pub optional_timezone: bool,
+    /// With `strict`, also reject values outside their component's range,
+    /// including days past the end of the month like `31/02/2025`
+    /// (default: false)
+    pub strict_values: bool,
+    /// Emit unpadded specifiers like `%-d` and `%-j` for positions whose
+    /// examples aren't zero-padded (default: false)
+    pub detect_padding: bool,
+    /// Recognize `noon` and `midnight` as times of day. They resolve to
+    /// [`TokenType::TimeWord`], emitted as `%H:%M`: replace them with
+    /// `12:00` / `00:00` before parsing (default: false)
+    pub allow_time_words: bool,
+    /// How positions that resolve to [`TokenType::Unknown`] appear in the
+    /// format: as literal text, as a `%?` wildcard or as an error
+    /// (default: [`UnknownPolicy::Literal`])
+    pub unknown_policy: UnknownPolicy,
+    /// Fail with [`DateInferError::MissingComponent`] unless the format has
+    /// a date part: a year, month, day or week (default: false)
+    pub require_date: bool,
+    /// Fail with [`DateInferError::MissingComponent`] unless the format has
+    /// a time part: an hour, minute, second or time word (default: false)
+    pub require_time: bool,
+    /// Accept ISO 8601's end-of-day hour, `24:00` / `24:00:00`, as `%H`.
+    /// Most strptime implementations reject it: roll such values over to
+    /// `00:00` of the next day before parsing (default: false)
+    pub allow_hour_24: bool,
+    /// Recognize abbreviated and fiscal years: `'25` and `FY25` resolve to
+    /// two-digit years, and `FY` stays a literal prefix (default: false)
+    pub fiscal_years: bool,
+    /// Skip inputs whose trimmed length in characters falls outside this
+    /// range, counting them in [`InferResult::skipped_count`]. Prose and
+    /// stray single characters are rejected before tokenizing; see
+    /// [`DEFAULT_LENGTH_FILTER`] (default: `None`)
+    pub length_filter: Option<RangeInclusive<usize>>,
+    /// Force token positions (by index into the tokenized date) to a type,
+    /// overriding consensus, e.g. `vec![(0, TokenType::Year4)]`. Each pin
+    /// must name an existing position and be compatible with a majority of
+    /// the examples (default: empty)
+    pub pinned: Vec<(usize, TokenType)>,
+    /// Fail with [`DateInferError::LooksLikeDuration`] when most inputs are
+    /// ISO 8601 durations such as `P1Y2M10DT2H30M` (default: false)
+    pub detect_duration: bool,
+    /// Fail with [`DateInferError::LooksLikeEpoch`] when most inputs are
+    /// Unix timestamps in seconds or milliseconds, including digit-grouped
+    /// (`1_705_312_200`) and scientific (`1.7053122e9`) spellings
+    /// (default: false)
+    pub detect_epoch: bool,
+    /// Fail with [`DateInferError::LooksLikeSeason`] when most inputs are
+    /// seasons or quarters with a year (`Spring 2025`, `Q2 2025`), which
+    /// strptime cannot represent (default: false)
+    pub detect_season: bool,
+    /// Read a single-digit position left over once day, month and year are
+    /// assigned as a weekday number: [`TokenType::WeekdayNumber`] (`%u`,
+    /// 1-7) or, when a 0 appears, [`TokenType::WeekdayNumberFromSunday`]
+    /// (`%w`, 0-6). Without it such a slot is read as an hour
+    /// (default: false)
+    pub weekday_numbers: bool,
+    /// Treat a column that starts with a 4-digit year as year-month-day:
+    /// the next two numeric positions are fixed to month then day without
+    /// weighing day-first evidence, and a row whose values don't fit fails
+    /// with [`DateInferError::NotIsoOrder`] (default: false)
+    pub assume_iso: bool,
+    /// Collapse the C locale's composite formats in the output: `%m/%d/%y`
+    /// becomes `%x` and `%H:%M:%S` becomes `%X`, for C `strftime` callers
+    /// (default: false)
+    pub locale_composites: bool,
+    /// Infer from the longest run of date components in each input,
+    /// dropping surrounding text such as the `ts=` and `level=info` of
+    /// `ts=2025-01-15T10:30:00 level=info` (default: false)
+    pub extract: bool,
+    /// With `strict`, stop as soon as the failures exceed
+    /// `strict_tolerance` (the first incompatible row, by default) instead
+    /// of counting them all; [`DateInferError::StrictValidationFailed`] then
+    /// reports the count at that point (default: false)
+    pub strict_fail_fast: bool,
+    /// Read decimal digits from other scripts (Arabic-Indic `٢٠٢٥`,
+    /// Devanagari `२०२५`, fullwidth `２０２５`, ...) as ASCII digits. Token
+    /// values hold the ASCII form; the format is unchanged (default: false)
+    pub unicode_digits: bool,
+    /// With `strict`, the fraction of rows allowed to be incompatible:
+    /// `0.01` fails only when more than 1% of rows don't match
+    /// (default: 0.0)
+    pub strict_tolerance: f64,
+    /// Called after each inference phase with the time it took, for
+    /// profiling slow columns. Timing is skipped entirely when unset
+    /// (default: None)
+    pub on_phase: Option<PhaseHook>,
+    /// For inputs over 1000 examples, start the evenly spaced sample at
+    /// offset `seed % step` instead of the first example, to audit a
+    /// different slice of a large column. Sampling is deterministic either
+    /// way: the same input and seed always select the same examples
+    /// (default: None)
+    pub sample_seed: Option<u64>,
+    /// Re-parse up to this many compatible examples with the inferred
+    /// format using chrono, failing with
+    /// [`DateInferError::VerificationFailed`] on the first that doesn't
+    /// parse. A safety net against formats that fit the tokens but not a
+    /// real parser. A `%?` wildcard reads each example's own text
+    /// (default: 0, off)
+    #[cfg(feature = "chrono")]
+    pub verify: usize,
+}
+
+/// A step of inference reported to [`InferOptions::on_phase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// Splitting the sampled inputs into tokens
+    Tokenize,
+    /// Voting on the type of each token position
+    Consensus,
+    /// Rewrite rules and pinned positions
+    Rules,
+    /// Building the strptime format string
+    Format,
+    /// Checking every input against the format (only with `strict`)
+    Strict,
+}
+
+/// Callback for [`InferOptions::on_phase`].
+#[derive(Clone)]
+pub struct PhaseHook(pub Arc<dyn Fn(Phase, Duration) + Send + Sync>);
+
+impl PhaseHook {
+    /// Wrap a closure taking the finished phase and its duration.
+    pub fn new(f: impl Fn(Phase, Duration) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+}
+
+impl std::fmt::Debug for PhaseHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PhaseHook(..)")
+    }
+}
+
+/// Run `f`, reporting its duration to the phase hook when one is set.
+fn timed<T>(options: &InferOptions, phase: Phase, f: impl FnOnce() -> T) -> T {
+    match &options.on_phase {
+        Some(hook) => {
+            let start = Instant::now();
+            let out = f();
+            (hook.0)(phase, start.elapsed());
+            out
+        }
+        None => f(),
+    }
 }
 
 impl Default for InferOptions {
@@ -54,12 +234,52 @@ impl Default for InferOptions {
             prefer_dayfirst: true,
             min_confidence: 0.0,
             strict: false,
+            skip_nulls: false,
+            null_values: Vec::new(),
+            year_range: DEFAULT_YEAR_RANGE,
+            case_sensitive: false,
+            optional_seconds: false,
+            optional_timezone: false,
+            strict_values: false,
+            detect_padding: false,
+            allow_time_words: false,
+            allow_hour_24: false,
+            unknown_policy: UnknownPolicy::Literal,
+            require_date: false,
+            require_time: false,
+            fiscal_years: false,
+            length_filter: None,
+            pinned: Vec::new(),
+            detect_duration: false,
+            detect_epoch: false,
+            detect_season: false,
+            weekday_numbers: false,
+            assume_iso: false,
+            locale_composites: false,
+            extract: false,
+            strict_fail_fast: false,
+            unicode_digits: false,
+            strict_tolerance: 0.0,
+            on_phase: None,
+            sample_seed: None,
+            #[cfg(feature = "chrono")]
+            verify: 0,
         }
     }
 }
 
+/// A [`InferOptions::length_filter`] that fits every supported format:
+/// from `5/3` up to a nanosecond timestamp with a weekday and timezone.
+pub const DEFAULT_LENGTH_FILTER: RangeInclusive<usize> = 3..=40;
+
+/// Values treated as explicit nulls when [`InferOptions::skip_nulls`] is set.
+///
+/// Matching is case-insensitive and ignores surrounding whitespace, so
+/// `"  "`, `"n/a"` and `"NULL"` are all nulls.
+pub const DEFAULT_NULL_VALUES: &[&str] = &["", "N/A", "NA", "null", "None", "NaN", "NaT"];
+
 /// Result of date format inference
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct InferResult {
     /// The inferred strptime format string
     pub format: String,
@@ -67,6 +287,190 @@ pub struct InferResult {
     pub confidence: f64,
     /// Resolved token types for each position
     pub token_types: Vec<TokenType>,
+    /// Number of inputs skipped as nulls before inference
+    pub skipped_count: usize,
+    /// Format of the minority rows when an optional component was folded
+    /// into the result, e.g. `%H:%M` alongside `%H:%M:%S` with
+    /// [`InferOptions::optional_seconds`], or the format without its
+    /// timezone with [`InferOptions::optional_timezone`]
+    pub alternate_format: Option<String>,
+    /// The day/month order was chosen by [`InferOptions::prefer_dayfirst`]
+    /// because no example proved it (every day and month value was 12 or
+    /// less). The day and month positions then count at half confidence
+    pub used_preference: bool,
+}
+
+impl InferResult {
+    /// Combine results inferred separately on shards of the same column.
+    ///
+    /// All results must have identical `token_types`. The merged confidence
+    /// is the mean of the shard confidences and `skipped_count` is summed.
+    ///
+    /// # Errors
+    ///
+    /// * [`DateInferError::EmptyInput`] if `results` is empty
+    /// * [`DateInferError::InconsistentFormats`] if the shards disagree on structure
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastdateinfer::{infer, InferResult};
+    ///
+    /// let a = infer(&["15/03/2025", "20/04/2025"]).unwrap();
+    /// let b = infer(&["01/02/2025", "25/12/2025"]).unwrap();
+    /// let merged = InferResult::merge(&[a, b]).unwrap();
+    /// assert_eq!(merged.format, "%d/%m/%Y");
+    /// ```
+    pub fn merge(results: &[InferResult]) -> Result<InferResult> {
+        let first = results.first().ok_or(DateInferError::EmptyInput)?;
+        if results.iter().any(|r| r.token_types != first.token_types) {
+            let mut length_counts = BTreeMap::new();
+            for r in results {
+                *length_counts.entry(r.token_types.len()).or_insert(0) += 1;
+            }
+            return Err(DateInferError::InconsistentFormats { length_counts });
+        }
+
+        let confidence = results.iter().map(|r| r.confidence).sum::<f64>() / results.len() as f64;
+        Ok(InferResult {
+            format: first.format.clone(),
+            confidence,
+            token_types: first.token_types.clone(),
+            skipped_count: results.iter().map(|r| r.skipped_count).sum(),
+            alternate_format: first.alternate_format.clone(),
+            used_preference: results.iter().all(|r| r.used_preference),
+        })
+    }
+
+    /// Return the format with every separator between two date components
+    /// replaced by `separator`.
+    ///
+    /// Only `/`, `.`, `-` and spaces are replaced. Time separators are left
+    /// alone, so `:` never becomes `/`, and so are multi-character literals
+    /// such as the `, ` in `%b %d, %Y` and markers such as `年`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastdateinfer::infer;
+    ///
+    /// let result = infer(&["15.03.2025 10:30", "20.04.2025 14:45"]).unwrap();
+    /// assert_eq!(result.canonicalize('-'), "%d-%m-%Y %H:%M");
+    /// ```
+    pub fn canonicalize(&self, separator: char) -> String {
+        format::canonicalize(&self.format, separator)
+    }
+
+    /// The format spelled for a platform's strftime / strptime.
+    ///
+    /// Only unpadded specifiers differ: `%-d` (from
+    /// [`InferOptions::detect_padding`]) stays `%-d` for
+    /// [`FormatFlavor::Posix`], becomes `%d` for
+    /// [`FormatFlavor::PythonStrptime`] and `%#d` for
+    /// [`FormatFlavor::WindowsStrftime`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastdateinfer::{infer_with_options, FormatFlavor, InferOptions};
+    ///
+    /// let options = InferOptions { detect_padding: true, ..Default::default() };
+    /// let result = infer_with_options(&["5/3/2025", "20/4/2025"], &options).unwrap();
+    /// assert_eq!(result.format_for(FormatFlavor::WindowsStrftime), "%#d/%#m/%Y");
+    /// ```
+    pub fn format_for(&self, flavor: FormatFlavor) -> String {
+        format::to_flavor(&self.format, flavor)
+    }
+
+    /// An anchored regex matching the inferred structure, for filtering
+    /// rows without a strptime call.
+    ///
+    /// Each specifier becomes a capture group: numbers match up to their
+    /// width (`%d` is `(\d{1,2})`), names match the built-in tables
+    /// case-insensitively and literals are escaped. The syntax works with
+    /// Rust's `regex` crate and Python's `re`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastdateinfer::infer;
+    ///
+    /// let result = infer(&["15/03/2025", "20/04/2025"]).unwrap();
+    /// assert_eq!(result.to_regex(), r"^(\d{1,2})/(\d{1,2})/(\d{4})$");
+    /// ```
+    pub fn to_regex(&self) -> String {
+        format::to_regex(&self.format)
+    }
+
+    /// Describe the format in prose for people who don't read strptime.
+    ///
+    /// Spaces, commas and the ISO `T` split the date into groups. Within a
+    /// group, components keep their separators, and a group of several
+    /// components names its separator, e.g. `day/month/4-digit year,
+    /// slash-separated`. Literal text shows as `(text)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastdateinfer::infer;
+    ///
+    /// let result = infer(&["15/03/2025", "20/04/2025"]).unwrap();
+    /// assert_eq!(result.describe(), "day/month/4-digit year, slash-separated");
+    /// ```
+    pub fn describe(&self) -> String {
+        // Each group: its text and the separator joining its components.
+        // Adjacent components with nothing between them (`%SZ`) also start
+        // a new group.
+        let mut groups: Vec<(String, Option<char>)> = vec![(String::new(), None)];
+        let mut after_component = false;
+        for token_type in &self.token_types {
+            let (text, separator) = groups.last_mut().expect("groups is never empty");
+            match token_type {
+                TokenType::Separator(' ' | ',' | 'T') => {
+                    if !text.is_empty() {
+                        groups.push((String::new(), None));
+                    }
+                    after_component = false;
+                }
+                TokenType::Separator(c) => {
+                    if !text.is_empty() {
+                        text.push(*c);
+                        separator.get_or_insert(*c);
+                    }
+                    after_component = false;
+                }
+                _ => {
+                    let name = match token_type {
+                        TokenType::Unknown => "(text)",
+                        _ => token_type.description(),
+                    };
+                    if after_component {
+                        groups.push((name.to_string(), None));
+                    } else {
+                        text.push_str(name);
+                    }
+                    after_component = true;
+                }
+            }
+        }
+
+        let mut parts = Vec::new();
+        for (text, separator) in groups.into_iter().filter(|(text, _)| !text.is_empty()) {
+            parts.push(text);
+            if let Some(c) = separator {
+                let name = match c {
+                    '/' => "slash".to_string(),
+                    '-' => "dash".to_string(),
+                    '.' => "dot".to_string(),
+                    ':' => "colon".to_string(),
+                    '_' => "underscore".to_string(),
+                    c => format!("'{c}'"),
+                };
+                parts.push(format!("{name}-separated"));
+            }
+        }
+        parts.join(", ")
+    }
 }
 
 /// Infer date format from a list of example date strings.
@@ -117,18 +521,370 @@ pub fn infer<S: AsRef<str>>(dates: &[S]) -> Result<InferResult> {
 /// assert_eq!(result.format, "%m/%d/%Y");
 /// ```
 pub fn infer_with_options<S: AsRef<str>>(dates: &[S], options: &InferOptions) -> Result<InferResult> {
+    infer_impl(&mut InferContext::default(), dates, options, true)
+}
+
+/// Reusable scratch buffers for [`infer_with_context`].
+///
+/// Each inference allocates token vectors for the sampled dates and a vote
+/// map per token position. A context keeps those allocations between calls,
+/// which matters when inferring many small columns in a loop. It carries no
+/// state that affects results, so one context can serve any mix of columns
+/// and options.
+#[derive(Debug, Default)]
+pub struct InferContext {
+    /// Tokenized sample rows; an empty row failed to tokenize
+    rows: Vec<Vec<Token>>,
+    /// Tokens of the date being checked by strict validation
+    scratch: Vec<Token>,
+    /// Per-position vote counts for consensus
+    votes: PositionVotes,
+    /// Row shapes the last inferred format accepts
+    shapes: RowShapes,
+}
+
+impl InferContext {
+    /// Create an empty context; buffers grow on first use
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Infer date format with custom options, reusing the buffers in `ctx`.
+///
+/// Returns the same result as [`infer_with_options`].
+///
+/// # Example
+///
+/// ```
+/// use fastdateinfer::{infer_with_context, InferContext, InferOptions};
+///
+/// let mut ctx = InferContext::new();
+/// let options = InferOptions::default();
+/// for column in [["15/03/2025", "01/02/2025"], ["2025-01-15", "2025-03-20"]] {
+///     let result = infer_with_context(&mut ctx, &column, &options).unwrap();
+///     println!("{}", result.format);
+/// }
+/// ```
+pub fn infer_with_context<S: AsRef<str>>(
+    ctx: &mut InferContext,
+    dates: &[S],
+    options: &InferOptions,
+) -> Result<InferResult> {
+    infer_impl(ctx, dates, options, true)
+}
+
+/// Infer date format with custom options, also returning the votes that
+/// consensus counted at each position.
+///
+/// The votes cover the sampled rows sharing the majority token structure,
+/// in the same shape as [`analyze`]. Use them to build your own confidence
+/// metric; [`infer_with_options`] skips copying them out.
+///
+/// # Example
+///
+/// ```
+/// use fastdateinfer::{infer_with_votes, InferOptions, TokenType};
+///
+/// let (result, votes) = infer_with_votes(&["01/02/2025", "15/04/2025"], &InferOptions::default()).unwrap();
+/// assert_eq!(result.format, "%d/%m/%Y");
+/// assert!(votes[0].candidates.contains(&(TokenType::Day, 2)));
+/// ```
+pub fn infer_with_votes<S: AsRef<str>>(
+    dates: &[S],
+    options: &InferOptions,
+) -> Result<(InferResult, Vec<PositionAnalysis>)> {
+    let mut ctx = InferContext::default();
+    let result = infer_impl(&mut ctx, dates, options, true)?;
+    Ok((result, analyze_votes(&ctx.votes)))
+}
+
+/// Infer date format, returning a best guess instead of an error.
+///
+/// Messy input that [`infer`] would reject with
+/// [`DateInferError::InconsistentFormats`] still produces the result for the
+/// largest cluster of same-structure dates, with the uncertainty reflected in
+/// a low confidence. Empty or entirely unparseable input returns an empty
+/// format with confidence 0.0.
+///
+/// # Example
+///
+/// ```
+/// use fastdateinfer::infer_best_effort;
+///
+/// let dates = vec!["15/03/2025", "2025-01-15T10:30:00", "Jan 2025", "20/04/2025"];
+/// let result = infer_best_effort(&dates);
+/// assert_eq!(result.format, "%d/%m/%Y");
+/// assert!(result.confidence <= 0.5);
+/// ```
+pub fn infer_best_effort<S: AsRef<str>>(dates: &[S]) -> InferResult {
+    infer_best_effort_with_options(dates, &InferOptions::default())
+}
+
+/// Infer date format with custom options, returning a best guess instead of
+/// an error.
+///
+/// `min_confidence` and `strict` are ignored: a best-effort result reports
+/// its uncertainty through `confidence` rather than failing. Options that
+/// reject the input (`pinned`, `assume_iso`, the `detect_*` checks,
+/// `require_date` / `require_time`, [`UnknownPolicy::Error`] and `verify`)
+/// are dropped when they would, and the guess is made without them.
+pub fn infer_best_effort_with_options<S: AsRef<str>>(dates: &[S], options: &InferOptions) -> InferResult {
+    let options = InferOptions {
+        min_confidence: 0.0,
+        strict: false,
+        ..options.clone()
+    };
+    let mut ctx = InferContext::default();
+    infer_impl(&mut ctx, dates, &options, false)
+        .or_else(|_| {
+            let relaxed = InferOptions {
+                pinned: Vec::new(),
+                assume_iso: false,
+                detect_duration: false,
+                detect_epoch: false,
+                detect_season: false,
+                require_date: false,
+                require_time: false,
+                unknown_policy: UnknownPolicy::Literal,
+                #[cfg(feature = "chrono")]
+                verify: 0,
+                ..options
+            };
+            infer_impl(&mut ctx, dates, &relaxed, false)
+        })
+        .unwrap_or_default()
+}
+
+/// Infer a format, then split the rows by whether they match it.
+///
+/// Returns the result with the indices of the rows that fit the inferred
+/// structure and value ranges (as with [`InferOptions::strict_values`])
+/// and of those that don't. Rows are checked as strict validation checks
+/// them, so the optional seconds and timezone shapes fit too.
+///
+/// # Example
+///
+/// ```
+/// use fastdateinfer::infer_and_partition;
+///
+/// let (result, valid, invalid) = infer_and_partition(&["15/03/2025", "oops", "20/04/2025"]).unwrap();
+/// assert_eq!(result.format, "%d/%m/%Y");
+/// assert_eq!(valid, [0, 2]);
+/// assert_eq!(invalid, [1]);
+/// ```
+pub fn infer_and_partition<S: AsRef<str>>(dates: &[S]) -> Result<(InferResult, Vec<usize>, Vec<usize>)> {
+    infer_and_partition_with_options(dates, &InferOptions::default())
+}
+
+/// Infer a format with custom options, then split the rows by whether
+/// they match it.
+///
+/// `strict` is ignored, since the partition already reports every
+/// mismatch. Nulls skipped by [`InferOptions::skip_nulls`] or
+/// [`InferOptions::null_values`] count as invalid.
+pub fn infer_and_partition_with_options<S: AsRef<str>>(
+    dates: &[S],
+    options: &InferOptions,
+) -> Result<(InferResult, Vec<usize>, Vec<usize>)> {
+    let options = InferOptions {
+        strict: false,
+        ..options.clone()
+    };
+    let mut ctx = InferContext::default();
+    let result = infer_impl(&mut ctx, dates, &options, true)?;
+
+    let (mut valid, mut invalid) = (Vec::new(), Vec::new());
+    let tokens = &mut ctx.scratch;
+    for (i, date) in dates.iter().enumerate() {
+        let date = date.as_ref();
+        let date = if options.extract { extract_date(date, &options) } else { date };
+        let matches = !is_null_value(date, &options)
+            && tokenize_into(date, &options, tokens).is_ok()
+            && ctx.shapes.accepts(tokens, true);
+        if matches {
+            valid.push(i);
+        } else {
+            invalid.push(i);
+        }
+    }
+    Ok((result, valid, invalid))
+}
+
+/// Estimate how date-like a column is, without running consensus.
+///
+/// Returns the fraction of inputs (sampled like [`infer`] for large
+/// columns) that tokenize into a plausible date: at least one year or
+/// month component, at least two date components (so lone integers and
+/// IDs don't count), and more date components than unrecognized words.
+/// Use it to skip free-text columns before inferring; `0.0` for no input.
+///
+/// # Example
+///
+/// ```
+/// use fastdateinfer::looks_like_dates;
+///
+/// assert_eq!(looks_like_dates(&["15/03/2025", "2025-01-15"]), 1.0);
+/// assert_eq!(looks_like_dates(&["hello world", "see you at 3"]), 0.0);
+/// ```
+pub fn looks_like_dates<S: AsRef<str>>(dates: &[S]) -> f64 {
+    let sample = sample_dates(dates, None);
+    if sample.is_empty() {
+        return 0.0;
+    }
+    let options = InferOptions::default();
+    let mut tokens = Vec::new();
+    let plausible = sample
+        .iter()
+        .filter(|d| tokenize_into(d.as_ref(), &options, &mut tokens).is_ok() && is_plausible_date(&tokens))
+        .count();
+    plausible as f64 / sample.len() as f64
+}
+
+/// Check whether tokens hold a year or month, at least two date components
+/// (so integer and ID columns don't count) and mostly date components.
+fn is_plausible_date(tokens: &[Token]) -> bool {
+    let mut has_year_or_month = false;
+    let (mut components, mut words) = (0, 0);
+    for token in tokens.iter().filter(|t| !t.is_separator()) {
+        let types = &token.possible_types;
+        if types.iter().any(|t| {
+            matches!(
+                t,
+                TokenType::Year4 | TokenType::MonthName | TokenType::MonthNameShort | TokenType::DayOrMonth
+            )
+        }) {
+            has_year_or_month = true;
+        }
+        if types.iter().all(|t| *t == TokenType::Unknown) {
+            words += 1;
+        } else {
+            components += 1;
+        }
+    }
+    has_year_or_month && components >= 2 && components > words
+}
+
+/// Shared entry point for [`infer_with_options`] and the best-effort API.
+///
+/// With `require_majority` unset, the largest token-length cluster is used
+/// even when it is not a strict majority.
+fn infer_impl<S: AsRef<str>>(
+    ctx: &mut InferContext,
+    dates: &[S],
+    options: &InferOptions,
+    require_majority: bool,
+) -> Result<InferResult> {
     if dates.is_empty() {
         return Err(DateInferError::EmptyInput);
     }
 
-    // Phase 1: Tokenize dates (sample for large inputs - consensus converges quickly)
+    if options.skip_nulls || !options.null_values.is_empty() || options.length_filter.is_some() || options.extract {
+        let present: Vec<&str> = dates
+            .iter()
+            .map(|d| if options.extract { extract_date(d.as_ref(), options) } else { d.as_ref() })
+            .filter(|d| !is_null_value(d, options))
+            .filter(|d| within_length_filter(d, options))
+            .collect();
+        if present.is_empty() {
+            return Err(DateInferError::EmptyInput);
+        }
+        let skipped_count = dates.len() - present.len();
+        let mut result = infer_filtered(ctx, &present, options, require_majority)?;
+        result.skipped_count = skipped_count;
+        return Ok(result);
+    }
+
+    infer_filtered(ctx, dates, options, require_majority)
+}
+
+/// Check if a value is one of [`InferOptions::null_values`], or of the
+/// [`DEFAULT_NULL_VALUES`] markers with `skip_nulls`.
+fn is_null_value(value: &str, options: &InferOptions) -> bool {
+    let value = value.trim();
+    (options.skip_nulls && DEFAULT_NULL_VALUES.iter().any(|n| n.eq_ignore_ascii_case(value)))
+        || options.null_values.iter().any(|n| n.trim().eq_ignore_ascii_case(value))
+}
+
+/// Find the longest run of date components in `value` for
+/// [`InferOptions::extract`], returning `value` unchanged when it has none.
+///
+/// A run is broken by unrecognized words; separators inside it are kept
+/// and those at either end dropped, except a closing CJK marker (`日`).
+fn extract_date<'a>(value: &'a str, options: &InferOptions) -> &'a str {
+    let Ok(tokens) = tokenize_with_options(value, options) else {
+        return value;
+    };
+
+    // (component count, first token, last token)
+    let mut best: Option<(usize, usize, usize)> = None;
+    let mut run: Option<(usize, usize, usize)> = None;
+    for (i, token) in tokens.iter().enumerate() {
+        if token.is_separator() {
+            continue;
+        }
+        if token.possible_types.iter().all(|t| *t == TokenType::Unknown) {
+            run = None;
+            continue;
+        }
+        let current = run.get_or_insert((0, i, i));
+        current.0 += 1;
+        current.2 = i;
+        if best.map_or(true, |(count, ..)| current.0 > count) {
+            best = Some(*current);
+        }
+    }
+
+    let Some((_, first, last)) = best else {
+        return value;
+    };
+    let last = last + tokens[last + 1..].iter().take_while(|t| t.value.chars().all(is_cjk_marker)).count();
+    // Token positions count characters and wrap past u16::MAX. Tokens come
+    // in order, so each starts at the first position with its wrapped value
+    // that is not inside the previous token
+    const WRAP: usize = u16::MAX as usize + 1;
+    let mut next = 0;
+    let positions: Vec<usize> = tokens
+        .iter()
+        .map(|t| {
+            let mut position = next - next % WRAP + t.position as usize;
+            if position < next {
+                position += WRAP;
+            }
+            next = position + t.value.chars().count();
+            position
+        })
+        .collect();
+    let byte_offset = |chars: usize| value.char_indices().nth(chars).map_or(value.len(), |(i, _)| i);
+    let start = byte_offset(positions[first]);
+    let end = byte_offset(positions[last] + tokens[last].value.chars().count());
+    value.get(start..end).unwrap_or(value)
+}
+
+/// Check a value against [`InferOptions::length_filter`].
+fn within_length_filter(value: &str, options: &InferOptions) -> bool {
+    options
+        .length_filter
+        .as_ref()
+        .map_or(true, |range| range.contains(&value.trim().chars().count()))
+}
+
+/// Select the examples to tokenize: all of them up to 1000, otherwise an
+/// evenly spaced sample with disambiguating dates injected.
+///
+/// The selection depends only on the input and `seed`: every `step`-th
+/// example from offset `seed % step` (0 without a seed), then the last one
+/// or two slots replaced by the first dates with a value > 12 in each
+/// day/month slot, unless the sample already holds them.
+fn sample_dates<S: AsRef<str>>(dates: &[S], seed: Option<u64>) -> Vec<&S> {
     const MAX_SAMPLE: usize = 1000;
-    let sample: Vec<&S> = if dates.len() <= MAX_SAMPLE {
+    if dates.len() <= MAX_SAMPLE {
         dates.iter().collect()
     } else {
         // Take evenly distributed sample: first, last, and evenly spaced middle
         let step = dates.len() / MAX_SAMPLE;
-        let mut sample: Vec<&S> = dates.iter().step_by(step).take(MAX_SAMPLE).collect();
+        let offset = seed.map_or(0, |seed| (seed % step as u64) as usize);
+        let mut sample: Vec<&S> = dates[offset..].iter().step_by(step).take(MAX_SAMPLE).collect();
 
         // Pre-scan ALL dates for disambiguating values (value > 12) that the
         // step_by sample may have missed. At most 2 replacements in the sample.
@@ -137,7 +893,9 @@ pub fn infer_with_options<S: AsRef<str>>(dates: &[S], options: &InferOptions) ->
         for (pos, opt_idx) in disambig.iter().enumerate() {
             if let Some(idx) = opt_idx {
                 // Only inject if this date isn't already in the sample
-                let already_sampled = *idx % step == 0 && *idx / step < sample_len;
+                let already_sampled = idx
+                    .checked_sub(offset)
+                    .is_some_and(|rel| rel % step == 0 && rel / step < sample_len);
                 if !already_sampled && sample_len > pos {
                     sample[sample_len - 1 - pos] = &dates[*idx];
                 }
@@ -145,69 +903,215 @@ pub fn infer_with_options<S: AsRef<str>>(dates: &[S], options: &InferOptions) ->
         }
 
         sample
-    };
+    }
+}
 
-    let tokenized_results: Vec<_> = sample
+/// Number of examples with each token count
+fn length_counts<'a>(tokenized: impl IntoIterator<Item = &'a Vec<Token>>) -> BTreeMap<usize, usize> {
+    let mut length_counts = BTreeMap::new();
+    for t in tokenized {
+        *length_counts.entry(t.len()).or_insert(0) += 1;
+    }
+    length_counts
+}
+
+/// Most common token count and how many examples have it.
+///
+/// Ties (only possible without a majority) go to the longer structure.
+fn majority_length(length_counts: &BTreeMap<usize, usize>) -> (usize, usize) {
+    length_counts
         .iter()
-        .map(|d| tokenize(d.as_ref()).ok())
-        .collect();
+        .map(|(&len, &count)| (len, count))
+        .max_by_key(|&(len, count)| (count, len))
+        .unwrap_or((0, 0))
+}
 
-    // Count token lengths to find majority
-    let mut length_counts: FxHashMap<usize, usize> = FxHashMap::default();
-    for t in tokenized_results.iter().flatten() {
-        *length_counts.entry(t.len()).or_insert(0) += 1;
+/// Run inference on dates that survived pre-filtering.
+fn infer_filtered<S: AsRef<str>>(
+    ctx: &mut InferContext,
+    dates: &[S],
+    options: &InferOptions,
+    require_majority: bool,
+) -> Result<InferResult> {
+    // Phase 1: Tokenize dates (sample for large inputs - consensus converges quickly)
+    let sample = sample_dates(dates, options.sample_seed);
+
+    check_non_dates(&sample, options.detect_duration, constraints::is_iso_duration, |duration_count, total_count| {
+        DateInferError::LooksLikeDuration { duration_count, total_count }
+    })?;
+    check_non_dates(&sample, options.detect_epoch, constraints::is_epoch, |epoch_count, total_count| {
+        DateInferError::LooksLikeEpoch { epoch_count, total_count }
+    })?;
+    check_non_dates(&sample, options.detect_season, constraints::is_season, |season_count, total_count| {
+        DateInferError::LooksLikeSeason { season_count, total_count }
+    })?;
+
+    // Tokenize into the context's rows. Rows that fail to tokenize are left
+    // empty, as are rows of only separators ("  ", "--"), which carry no
+    // date components
+    let sample_count = sample.len();
+    if ctx.rows.len() < sample_count {
+        ctx.rows.resize_with(sample_count, Vec::new);
     }
+    let rows = &mut ctx.rows[..sample_count];
+    timed(options, Phase::Tokenize, || {
+        for (tokens, date) in rows.iter_mut().zip(&sample) {
+            if tokenize_into(date.as_ref(), options, tokens).is_err() || tokens.iter().all(|t| t.is_separator()) {
+                tokens.clear();
+            }
+        }
+    });
 
-    let sample_count = tokenized_results.len();
-    let (majority_len, majority_count) = length_counts
-        .into_iter()
-        .max_by_key(|&(_, count)| count)
-        .unwrap_or((0, 0));
+    // Strip a trailing timezone from rows that are otherwise as long as
+    // rows without one, grouping the stripped suffixes by shape (`Z` with
+    // the offsets, which `%z` also reads) so the format takes the majority
+    let mut with_tz = 0;
+    let mut tz_groups: Vec<TzSuffixGroup> = Vec::new();
+    if options.optional_timezone {
+        let bare_lens: Vec<usize> = rows
+            .iter()
+            .filter(|t| !t.is_empty() && tz_suffix_len(t) == 0)
+            .map(|t| t.len())
+            .collect();
+        for tokens in rows.iter_mut() {
+            let suffix_len = tz_suffix_len(tokens);
+            if suffix_len > 0 && bare_lens.contains(&(tokens.len() - suffix_len)) {
+                let bare_len = tokens.len() - suffix_len;
+                let suffix = &tokens[bare_len..];
+                let shape: Vec<TokenType> = suffix.iter().map(tz_suffix_shape).collect();
+                match tz_groups.iter_mut().find(|g| g.shape == shape) {
+                    Some(group) => group.add(suffix),
+                    None => tz_groups.push(TzSuffixGroup::new(shape, suffix)),
+                }
+                tokens.truncate(bare_len);
+                with_tz += 1;
+            }
+        }
+    }
+
+    // Fold HH:MM:SS rows onto the HH:MM rows they extend, so both shapes
+    // vote together on the shared prefix
+    let mut with_seconds = 0;
+    if options.optional_seconds {
+        let short_lens: Vec<usize> = rows
+            .iter()
+            .filter(|t| ends_with_hour_minute(t) && !ends_with_seconds(t))
+            .map(|t| t.len())
+            .collect();
+        for tokens in rows.iter_mut() {
+            if ends_with_seconds(tokens) && short_lens.contains(&(tokens.len() - 2)) {
+                tokens.truncate(tokens.len() - 2);
+                with_seconds += 1;
+            }
+        }
+    }
 
+    let length_counts = length_counts(rows.iter().filter(|t| !t.is_empty()));
+    let (majority_len, majority_count) = majority_length(&length_counts);
+
+    if majority_count == 0 {
+        return Err(DateInferError::NoDateTokens { input_count: dates.len() });
+    }
     // Require >50% of tokenizable dates to have the majority length
-    if majority_count * 2 <= sample_count {
-        return Err(DateInferError::InconsistentFormats);
+    if require_majority && majority_count * 2 <= sample_count {
+        return Err(DateInferError::InconsistentFormats { length_counts });
     }
 
     let filter_ratio = majority_count as f64 / sample_count as f64;
 
-    // Filter to only majority-length tokenized dates
-    let tokenized: Vec<Vec<Token>> = tokenized_results
-        .into_iter()
-        .filter_map(|t| t.filter(|tokens| tokens.len() == majority_len))
-        .collect();
+    // Move majority-length rows to the front, keeping their order
+    let mut kept = 0;
+    for i in 0..sample_count {
+        if rows[i].len() == majority_len {
+            rows.swap(kept, i);
+            kept += 1;
+        }
+    }
+    let tokenized = &rows[..kept];
 
-    // Phase 2-3: Resolve consensus with constraints
-    let (mut resolved_types, raw_confidence) = resolve_consensus(&tokenized, options)?;
-    let confidence = raw_confidence * filter_ratio;
+    // Phase 2-5: Resolve types and generate the format
+    let InferResult {
+        mut format,
+        confidence,
+        token_types: mut resolved_types,
+        used_preference,
+        ..
+    } = resolve_tokens(tokenized, options, &mut ctx.votes)?;
+    // Rows whose timezone differs from the majority one don't fit the format
+    let tz_suffix = tz_groups
+        .iter()
+        .rev()
+        .max_by_key(|g| g.count)
+        .map(|g| g.offset.clone().unwrap_or_else(|| g.first.clone()))
+        .unwrap_or_default();
+    let tz_mismatched = with_tz - tz_groups.iter().map(|g| g.count).max().unwrap_or(0);
+    let confidence = confidence * filter_ratio * (1.0 - tz_mismatched as f64 / majority_count as f64);
+    // Rows are checked against the spellings of the rows that voted
+    ctx.shapes.template = spelling_template(tokenized, &resolved_types);
+    check_confidence(confidence, options)?;
+    check_components(&resolved_types, &format, options)?;
 
-    // Phase 4: Apply rewrite rules for remaining ambiguities
-    apply_rules(&mut resolved_types);
+    // Optional seconds: report the majority shape, keep the other as alternate
+    let mut alternate_types = None;
+    let mut alternate_format = None;
+    if with_seconds > 0 {
+        let mut long_types = resolved_types.clone();
+        long_types.extend([TokenType::Separator(':'), TokenType::Second]);
+        let long_format = format!("{format}:%S");
+        if with_seconds * 2 >= majority_count {
+            alternate_types = Some(std::mem::replace(&mut resolved_types, long_types));
+            alternate_format = Some(std::mem::replace(&mut format, long_format));
+        } else {
+            alternate_types = Some(long_types);
+            alternate_format = Some(long_format);
+        }
+    }
 
-    // Check minimum confidence
-    if confidence < options.min_confidence {
-        return Err(DateInferError::LowConfidence {
-            got: confidence,
-            required: options.min_confidence,
-        });
+    // Optional timezone: likewise, with the stripped suffix appended
+    let mut tz_alternate_types = None;
+    if with_tz > 0 {
+        let suffix_types: Vec<TokenType> = tz_suffix.iter().map(|t| t.possible_types[0]).collect();
+        let suffix_format = to_strptime(&tz_suffix, &suffix_types);
+        let mut tz_types = resolved_types.clone();
+        tz_types.extend(suffix_types);
+        let tz_format = format!("{format}{suffix_format}");
+        if with_tz * 2 >= majority_count {
+            let bare_types = std::mem::replace(&mut resolved_types, tz_types);
+            let bare_format = std::mem::replace(&mut format, tz_format);
+            tz_alternate_types = Some(bare_types);
+            alternate_format = alternate_format.or(Some(bare_format));
+        } else {
+            tz_alternate_types = Some(tz_types);
+            alternate_format = alternate_format.or(Some(tz_format));
+        }
     }
 
-    // Phase 5: Generate strptime format
-    let format = to_strptime(&tokenized[0], &resolved_types);
+    ctx.shapes.types.clear();
+    ctx.shapes.types.push(resolved_types.clone());
+    ctx.shapes.types.extend(alternate_types.into_iter().chain(tz_alternate_types));
 
     // Phase 6: Strict validation (if enabled)
     if options.strict {
-        let mut failed_count = 0;
-        for date in dates {
-            if let Ok(tokens) = tokenize(date.as_ref()) {
-                if !is_compatible(&tokens, &resolved_types) {
+        let too_many = |failed: usize| failed as f64 > options.strict_tolerance * dates.len() as f64;
+        let shapes = &ctx.shapes;
+        let tokens = &mut ctx.scratch;
+        let failed_count = timed(options, Phase::Strict, || {
+            let mut failed_count = 0;
+            for date in dates {
+                if tokenize_into(date.as_ref(), options, tokens).is_ok() {
+                    if !shapes.accepts(tokens, options.strict_values) {
+                        failed_count += 1;
+                    }
+                } else {
                     failed_count += 1;
                 }
-            } else {
-                failed_count += 1;
+                if options.strict_fail_fast && too_many(failed_count) {
+                    break;
+                }
             }
-        }
-        if failed_count > 0 {
+            failed_count
+        });
+        if too_many(failed_count) {
             return Err(DateInferError::StrictValidationFailed {
                 failed_count,
                 total_count: dates.len(),
@@ -215,259 +1119,2185 @@ pub fn infer_with_options<S: AsRef<str>>(dates: &[S], options: &InferOptions) ->
         }
     }
 
+    // Phase 7: Re-parse examples with the format (if enabled)
+    #[cfg(feature = "chrono")]
+    if options.verify > 0 {
+        verify_examples(dates, &resolved_types, &format, options, &mut ctx.scratch)?;
+    }
+
     Ok(InferResult {
         format,
         confidence,
         token_types: resolved_types,
+        skipped_count: 0,
+        alternate_format,
+        used_preference,
     })
 }
 
-/// Check if a token is compatible with a resolved type.
-/// Handles Day/DayOrMonth equivalence: a token that could be DayOrMonth
-/// is compatible with Day or Month resolved types.
-fn is_token_compatible(token: &Token, resolved: &TokenType) -> bool {
-    if token.possible_types.contains(resolved) {
-        return true;
-    }
-    // DayOrMonth equivalence: if the resolved type is Day or Month,
-    // a token with DayOrMonth in its possible types is compatible
-    match resolved {
-        TokenType::Day | TokenType::Month | TokenType::DayOrMonth => {
-            token.possible_types.iter().any(|t| matches!(t,
-                TokenType::Day | TokenType::Month | TokenType::DayOrMonth
-            ))
-        }
-        _ => false,
-    }
+/// List the candidate token types at each position, before consensus.
+///
+/// Uses the same sample and majority token structure as [`infer`], so the
+/// positions line up with [`InferResult::token_types`]. Returns an empty
+/// `Vec` when no input tokenizes.
+///
+/// # Example
+///
+/// ```
+/// use fastdateinfer::{analyze, TokenType};
+///
+/// let analysis = analyze(&["01/02/2025", "03/04/2025"]);
+/// assert_eq!(analysis.len(), 5);
+/// assert!(analysis[1].is_separator);
+/// assert!(analysis[0].candidates.contains(&(TokenType::DayOrMonth, 2)));
+/// ```
+pub fn analyze<S: AsRef<str>>(dates: &[S]) -> Vec<PositionAnalysis> {
+    analyze_with_options(dates, &InferOptions::default())
 }
 
-/// Check if a tokenized date is compatible with the resolved types.
-fn is_compatible(tokens: &[Token], resolved_types: &[TokenType]) -> bool {
-    if tokens.len() != resolved_types.len() {
-        return false;
-    }
-    tokens.iter().zip(resolved_types.iter()).all(|(token, resolved)| {
-        is_token_compatible(token, resolved)
-    })
+/// List the candidate token types at each position with custom options.
+pub fn analyze_with_options<S: AsRef<str>>(dates: &[S], options: &InferOptions) -> Vec<PositionAnalysis> {
+    analyze_positions(&majority_tokenized(dates, options))
 }
 
-#[cfg(feature = "python")]
-#[allow(clippy::useless_conversion)]
-mod python;
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_unambiguous_dmy() {
-        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025"];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d/%m/%Y");
-        assert!(result.confidence > 0.9);
+/// Explain how a single position was resolved.
+///
+/// A narrower alternative to [`analyze`] for interactive debugging: returns
+/// the candidate votes, the chosen type and its confidence for position
+/// `index`, or `None` if the inferred structure has no such position.
+///
+/// # Example
+///
+/// ```
+/// use fastdateinfer::{explain_position, InferOptions, TokenType};
+///
+/// let dates = ["01/02/2025", "15/03/2025"];
+/// let explanation = explain_position(&dates, &InferOptions::default(), 2).unwrap().unwrap();
+/// assert_eq!(explanation.resolved, TokenType::Month);
+/// assert_eq!(explanation.confidence, Some(1.0));
+/// ```
+pub fn explain_position<S: AsRef<str>>(
+    dates: &[S],
+    options: &InferOptions,
+    index: usize,
+) -> Result<Option<PositionExplanation>> {
+    if dates.is_empty() {
+        return Err(DateInferError::EmptyInput);
     }
-
-    #[test]
-    fn test_consensus_resolves_ambiguous() {
-        // 01/02 is ambiguous, but 15/03 proves it's DD/MM
-        let dates = vec!["01/02/2025", "15/03/2025"];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d/%m/%Y");
+    let tokenized = majority_tokenized(dates, options);
+    if tokenized.is_empty() {
+        return Err(DateInferError::NoValidPattern);
+    }
+
+    let (mut resolved_types, confidences, _) = resolve_consensus_by_position(&tokenized, options, &mut PositionVotes::new())?;
+    apply_rules(&mut resolved_types);
+    let Some(&resolved) = resolved_types.get(index) else {
+        return Ok(None);
+    };
+
+    let analysis = analyze_positions(&tokenized).swap_remove(index);
+    Ok(Some(PositionExplanation {
+        candidates: analysis.candidates,
+        resolved,
+        confidence: confidences[index],
+    }))
+}
+
+/// Tokenize a sample of `dates`, keeping examples with the majority structure.
+fn majority_tokenized<S: AsRef<str>>(dates: &[S], options: &InferOptions) -> Vec<Vec<Token>> {
+    let tokenized_results: Vec<_> = sample_dates(dates, options.sample_seed)
+        .iter()
+        .map(|d| tokenize_with_options(d.as_ref(), options).ok())
+        .collect();
+    let (majority_len, _) = majority_length(&length_counts(tokenized_results.iter().flatten()));
+    tokenized_results
+        .into_iter()
+        .filter_map(|t| t.filter(|tokens| tokens.len() == majority_len))
+        .collect()
+}
+
+/// Infer a date format from pre-tokenized input.
+///
+/// Runs consensus, rewrite rules and format generation directly on `tokenized`,
+/// bypassing the built-in tokenizer. Every example must have the same number
+/// of tokens; sampling, null skipping and strict validation are not applied.
+///
+/// # Errors
+///
+/// * [`DateInferError::EmptyInput`] if `tokenized` is empty
+/// * [`DateInferError::InconsistentFormats`] if token counts differ
+///
+/// # Example
+///
+/// ```
+/// use fastdateinfer::{infer_from_tokens, tokenize, InferOptions};
+///
+/// let tokenized = vec![tokenize("15/03/2025").unwrap(), tokenize("01/02/2025").unwrap()];
+/// let result = infer_from_tokens(&tokenized, &InferOptions::default()).unwrap();
+/// assert_eq!(result.format, "%d/%m/%Y");
+/// ```
+pub fn infer_from_tokens(tokenized: &[Vec<Token>], options: &InferOptions) -> Result<InferResult> {
+    let first = tokenized.first().ok_or(DateInferError::EmptyInput)?;
+    if tokenized.iter().any(|t| t.len() != first.len()) {
+        return Err(DateInferError::InconsistentFormats {
+            length_counts: length_counts(tokenized),
+        });
+    }
+
+    let result = resolve_tokens(tokenized, options, &mut PositionVotes::new())?;
+    check_confidence(result.confidence, options)?;
+    check_components(&result.token_types, &result.format, options)?;
+    Ok(result)
+}
+
+/// Phases 2-5 on same-length token lists: consensus, rules and format.
+fn resolve_tokens(tokenized: &[Vec<Token>], options: &InferOptions, votes: &mut PositionVotes) -> Result<InferResult> {
+    check_pins(tokenized, options)?;
+
+    let iso_options;
+    let options = match iso_pins(tokenized, options)? {
+        Some(pins) => {
+            let mut pinned = options.pinned.clone();
+            pinned.extend(pins.into_iter().filter(|&(index, _)| !options.pinned.iter().any(|&(i, _)| i == index)));
+            iso_options = InferOptions { pinned, ..options.clone() };
+            &iso_options
+        }
+        None => options,
+    };
+
+    // Phase 2-3: Resolve consensus with constraints
+    let (mut resolved_types, confidence, used_preference) =
+        timed(options, Phase::Consensus, || resolve_consensus(tokenized, options, votes))?;
+
+    // Phase 4: Apply rewrite rules for remaining ambiguities
+    timed(options, Phase::Rules, || {
+        apply_rules(&mut resolved_types);
+        for &(index, token_type) in &options.pinned {
+            resolved_types[index] = token_type;
+        }
+    });
+
+    if options.unknown_policy == UnknownPolicy::Error && resolved_types.contains(&TokenType::Unknown) {
+        return Err(DateInferError::NoValidPattern);
+    }
+
+    // Phase 5: Generate strptime format
+    let format = timed(options, Phase::Format, || {
+        let unpadded = if options.detect_padding {
+            unpadded_positions(tokenized, &resolved_types)
+        } else {
+            Vec::new()
+        };
+        let template = spelling_template(tokenized, &resolved_types);
+        let format = to_strptime_with_padding(&template, &resolved_types, &unpadded, options.unknown_policy);
+        if options.locale_composites {
+            collapse_locale_composites(&format)
+        } else {
+            format
+        }
+    });
+
+    Ok(InferResult {
+        format,
+        confidence,
+        token_types: resolved_types,
+        used_preference,
+        ..Default::default()
+    })
+}
+
+/// Fail with [`DateInferError::InvalidPin`] for a pinned position that
+/// doesn't exist or that most examples can't hold.
+fn check_pins(tokenized: &[Vec<Token>], options: &InferOptions) -> Result<()> {
+    for &(index, token_type) in &options.pinned {
+        let compatible = tokenized
+            .iter()
+            .filter(|tokens| tokens.get(index).is_some_and(|t| is_token_compatible(t, &token_type)))
+            .count();
+        if compatible * 2 <= tokenized.len() {
+            return Err(DateInferError::InvalidPin { index, token_type });
+        }
+    }
+    Ok(())
+}
+
+/// With [`InferOptions::assume_iso`], find the month and day positions of
+/// a year-first column and check every example against them.
+///
+/// Returns `None` when the option is off or the column doesn't start with
+/// a 4-digit year.
+fn iso_pins(tokenized: &[Vec<Token>], options: &InferOptions) -> Result<Option<[(usize, TokenType); 2]>> {
+    if !options.assume_iso {
+        return Ok(None);
+    }
+    let first = &tokenized[0];
+    if !first.first().is_some_and(|t| t.numeric_value.is_some() && t.value.len() == 4) {
+        return Ok(None);
+    }
+    let mut components = first.iter().enumerate().skip(1).filter(|(_, t)| !t.is_separator());
+    let (Some((month, m)), Some((day, d))) = (components.next(), components.next()) else {
+        return Ok(None);
+    };
+    if m.numeric_value.is_none() || d.numeric_value.is_none() {
+        return Ok(None);
+    }
+
+    for tokens in tokenized {
+        let fits = |index: usize, max: u32| {
+            tokens[index].value.len() <= 2 && tokens[index].numeric_value.is_some_and(|v| (1..=max).contains(&v))
+        };
+        if !fits(month, 12) || !fits(day, 31) {
+            return Err(DateInferError::NotIsoOrder {
+                example: tokens.iter().map(|t| t.value.as_str()).collect(),
+            });
+        }
+    }
+    Ok(Some([(month, TokenType::Month), (day, TokenType::Day)]))
+}
+
+/// Fail with [`DateInferError::LowConfidence`] below `options.min_confidence`.
+fn check_confidence(confidence: f64, options: &InferOptions) -> Result<()> {
+    if confidence < options.min_confidence {
+        return Err(DateInferError::LowConfidence {
+            got: confidence,
+            required: options.min_confidence,
+        });
+    }
+    Ok(())
+}
+
+/// Fail when [`InferOptions::require_date`] or
+/// [`InferOptions::require_time`] asks for a part the types lack.
+fn check_components(types: &[TokenType], format: &str, options: &InferOptions) -> Result<()> {
+    let has_date = types.iter().any(|t| {
+        matches!(
+            t,
+            TokenType::Year4
+                | TokenType::Year2
+                | TokenType::Month
+                | TokenType::Day
+                | TokenType::DayOrMonth
+                | TokenType::MonthName
+                | TokenType::MonthNameShort
+                | TokenType::DayOfYear
+                | TokenType::IsoYear
+                | TokenType::IsoYear2
+                | TokenType::IsoWeek
+        )
+    });
+    let has_time = types.iter().any(|t| {
+        matches!(
+            t,
+            TokenType::Hour24 | TokenType::Hour12 | TokenType::Minute | TokenType::Second | TokenType::TimeWord
+        )
+    });
+    let missing = if options.require_date && !has_date {
+        "date"
+    } else if options.require_time && !has_time {
+        "time"
+    } else {
+        return Ok(());
+    };
+    Err(DateInferError::MissingComponent {
+        component: missing,
+        format: format.to_string(),
+    })
+}
+
+/// Check if tokens end in a `NN:NN` group.
+fn ends_with_hour_minute(tokens: &[Token]) -> bool {
+    let n = tokens.len();
+    n >= 3
+        && tokens[n - 1].numeric_value.is_some()
+        && tokens[n - 2].value == ":"
+        && tokens[n - 3].numeric_value.is_some()
+}
+
+/// Parse the first `options.verify` examples compatible with `types`
+/// using `format`, failing on the first that chrono rejects.
+#[cfg(feature = "chrono")]
+fn verify_examples<S: AsRef<str>>(
+    dates: &[S],
+    types: &[TokenType],
+    format: &str,
+    options: &InferOptions,
+    tokens: &mut Vec<Token>,
+) -> Result<()> {
+    let mut checked = 0;
+    for example in dates.iter().map(|d| d.as_ref()) {
+        if checked == options.verify {
+            break;
+        }
+        if tokenize_into(example, options, tokens).is_err() || !is_compatible(tokens, types) {
+            continue;
+        }
+        checked += 1;
+        // The text each `%?` stands for in this example
+        let wildcards: Vec<&str> = tokens
+            .iter()
+            .zip(types)
+            .filter(|(_, t)| **t == TokenType::Unknown)
+            .map(|(token, _)| token.value.as_str())
+            .collect();
+        if !parses_with(example, format, &wildcards) {
+            return Err(DateInferError::VerificationFailed {
+                example: example.to_string(),
+                format: format.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Check that chrono accepts `example` for `format`, including the range
+/// of each field (a month of 15 fails). Each `%?` of
+/// [`UnknownPolicy::Wildcard`], which chrono has no specifier for, is read
+/// as the literal text in `wildcards`, in order.
+#[cfg(feature = "chrono")]
+fn parses_with(example: &str, format: &str, wildcards: &[&str]) -> bool {
+    use chrono::format::{parse, Parsed, StrftimeItems};
+
+    // Time words are emitted as %H:%M
+    let mut text = example.trim().to_string();
+    for (word, time) in [("midnight", "00:00"), ("noon", "12:00")] {
+        if let Some(start) = text.to_ascii_lowercase().find(word) {
+            text.replace_range(start..start + word.len(), time);
+        }
+    }
+    // A trailing %z reads `Z` as Python's strptime does; chrono needs the
+    // offset spelled out
+    if format.ends_with("%z") && text.ends_with(['Z', 'z']) {
+        text.pop();
+        text.push_str("+00:00");
+    }
+    let mut filled = String::with_capacity(format.len());
+    let mut wildcards = wildcards.iter();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        match (c, c == '%' && chars.clone().next() == Some('?')) {
+            ('%', true) => {
+                chars.next();
+                let Some(text) = wildcards.next() else {
+                    return false;
+                };
+                filled.push_str(&text.replace('%', "%%"));
+            }
+            ('%', false) => {
+                filled.push('%');
+                filled.extend(chars.next());
+            }
+            _ => filled.push(c),
+        }
+    }
+    parse(&mut Parsed::new(), &text, StrftimeItems::new(&filled)).is_ok()
+}
+
+/// Number of trailing tokens that make up a timezone after a numeric
+/// component: the zone itself plus a separating space, or 0 for none.
+fn tz_suffix_len(tokens: &[Token]) -> usize {
+    let is_tz = |t: &Token| {
+        t.possible_types.len() == 1
+            && matches!(t.possible_types[0], TokenType::TzZ | TokenType::TzOffset | TokenType::TzName)
+    };
+    match tokens {
+        [.., before, last] if is_tz(last) && before.numeric_value.is_some() => 1,
+        [.., before, sep, last] if is_tz(last) && sep.value == " " && before.numeric_value.is_some() => 2,
+        _ => 0,
+    }
+}
+
+/// Stripped timezone suffixes of one shape, for
+/// [`InferOptions::optional_timezone`]
+struct TzSuffixGroup {
+    shape: Vec<TokenType>,
+    /// The first suffix of this shape
+    first: Vec<Token>,
+    /// The first suffix with a numeric offset, which `%z` is rendered from
+    /// even when a `Z` came first
+    offset: Option<Vec<Token>>,
+    count: usize,
+}
+
+impl TzSuffixGroup {
+    fn new(shape: Vec<TokenType>, suffix: &[Token]) -> Self {
+        let mut group = Self {
+            shape,
+            first: suffix.to_vec(),
+            offset: None,
+            count: 0,
+        };
+        group.add(suffix);
+        group
+    }
+
+    fn add(&mut self, suffix: &[Token]) {
+        self.count += 1;
+        if self.offset.is_none() && suffix.iter().any(|t| t.possible_types.contains(&TokenType::TzOffset)) {
+            self.offset = Some(suffix.to_vec());
+        }
+    }
+}
+
+/// The type a timezone suffix token is grouped by, with `Z` read as an
+/// offset
+fn tz_suffix_shape(token: &Token) -> TokenType {
+    match token.possible_types[0] {
+        TokenType::TzZ => TokenType::TzOffset,
+        t => t,
+    }
+}
+
+/// When `enabled`, fail with `error(count, total)` if most of the sample
+/// is values `matches` accepts, such as durations or timestamps rather
+/// than dates.
+fn check_non_dates<S: AsRef<str>>(
+    sample: &[&S],
+    enabled: bool,
+    matches: fn(&str) -> bool,
+    error: fn(usize, usize) -> DateInferError,
+) -> Result<()> {
+    if !enabled {
+        return Ok(());
+    }
+    let count = sample.iter().filter(|d| matches(d.as_ref())).count();
+    if count * 2 > sample.len() {
+        return Err(error(count, sample.len()));
+    }
+    Ok(())
+}
+
+/// Check if tokens end in a `NN:NN:NN` group.
+fn ends_with_seconds(tokens: &[Token]) -> bool {
+    let n = tokens.len();
+    n >= 5 && ends_with_hour_minute(tokens) && tokens[n - 4].value == ":" && tokens[n - 5].numeric_value.is_some()
+}
+
+/// Infer date formats for multiple columns.
+///
+/// Each column is inferred independently with the same options. Results are
+/// returned in column order; a failing column does not affect the others.
+///
+/// # Example
+///
+/// ```
+/// use fastdateinfer::{infer_columns, InferOptions};
+///
+/// let columns = vec![
+///     vec!["15/03/2025", "20/04/2025"],
+///     vec!["2025-01-15", "2025-03-20"],
+/// ];
+/// let results = infer_columns(&columns, &InferOptions::default());
+/// assert_eq!(results[0].as_ref().unwrap().format, "%d/%m/%Y");
+/// assert_eq!(results[1].as_ref().unwrap().format, "%Y-%m-%d");
+/// ```
+pub fn infer_columns<C, S>(columns: &[C], options: &InferOptions) -> Vec<Result<InferResult>>
+where
+    C: AsRef<[S]>,
+    S: AsRef<str>,
+{
+    infer_columns_with_progress(columns, options, &mut |_, _| {})
+}
+
+/// Infer date formats for multiple columns, reporting progress.
+///
+/// `progress` is called after each column completes with `(done, total)`,
+/// which makes it easy to drive a progress bar for long-running batch jobs.
+///
+/// # Example
+///
+/// ```
+/// use fastdateinfer::{infer_columns_with_progress, InferOptions};
+///
+/// let columns = vec![vec!["15/03/2025"], vec!["2025-01-15"]];
+/// let mut last = (0, 0);
+/// let results = infer_columns_with_progress(&columns, &InferOptions::default(), &mut |done, total| {
+///     last = (done, total);
+/// });
+/// assert_eq!(results.len(), 2);
+/// assert_eq!(last, (2, 2));
+/// ```
+pub fn infer_columns_with_progress<C, S>(
+    columns: &[C],
+    options: &InferOptions,
+    progress: &mut dyn FnMut(usize, usize),
+) -> Vec<Result<InferResult>>
+where
+    C: AsRef<[S]>,
+    S: AsRef<str>,
+{
+    let total = columns.len();
+    let mut results = Vec::with_capacity(total);
+    for (i, column) in columns.iter().enumerate() {
+        results.push(infer_with_options(column.as_ref(), options));
+        progress(i + 1, total);
+    }
+    results
+}
+
+/// Infer date formats for the fields of row-major records.
+///
+/// Rows are transposed into columns (a short row simply has no value in
+/// its missing columns) and each column is inferred with `options`.
+/// Columns that don't look like dates (see [`looks_like_dates`]), that
+/// resolve to a single component (an ID column of `1`, `2`, ... would
+/// otherwise read as `%d`) or whose inference fails are `None`.
+///
+/// # Example
+///
+/// ```
+/// use fastdateinfer::{infer_record_fields, InferOptions};
+///
+/// let rows = vec![
+///     vec!["alice", "15/03/2025", "42"],
+///     vec!["bob", "20/04/2025", "17"],
+/// ];
+/// let results = infer_record_fields(&rows, &InferOptions::default());
+/// assert!(results[0].is_none());
+/// assert_eq!(results[1].as_ref().unwrap().format, "%d/%m/%Y");
+/// assert!(results[2].is_none());
+/// ```
+pub fn infer_record_fields<R, S>(rows: &[R], options: &InferOptions) -> Vec<Option<InferResult>>
+where
+    R: AsRef<[S]>,
+    S: AsRef<str>,
+{
+    let width = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+    (0..width)
+        .map(|field| {
+            let column: Vec<&str> = rows.iter().filter_map(|row| row.as_ref().get(field)).map(|v| v.as_ref()).collect();
+            if looks_like_dates(&column) < 0.5 {
+                return None;
+            }
+            infer_with_options(&column, options)
+                .ok()
+                .filter(|result| result.token_types.iter().filter(|t| !matches!(t, TokenType::Separator(_))).count() >= 2)
+        })
+        .collect()
+}
+
+/// Check if a token is compatible with a resolved type.
+/// Handles Day/DayOrMonth equivalence: a token that could be DayOrMonth
+/// is compatible with Day or Month resolved types.
+fn is_token_compatible(token: &Token, resolved: &TokenType) -> bool {
+    if token.possible_types.contains(resolved) {
+        return true;
+    }
+    // `%z` also reads `Z`
+    if *resolved == TokenType::TzOffset {
+        return token.possible_types.contains(&TokenType::TzZ);
+    }
+    // Timezone abbreviations outside the built-in list classify as Unknown
+    if *resolved == TokenType::TzName {
+        return constraints::is_tz_abbreviation(&token.value);
+    }
+    // Weekday numbers classify as day/month numbers
+    match resolved {
+        TokenType::WeekdayNumber => return token.value.len() == 1 && token.numeric_value.is_some_and(|v| (1..=7).contains(&v)),
+        TokenType::WeekdayNumberFromSunday => return token.value.len() == 1 && token.numeric_value.is_some_and(|v| v <= 6),
+        _ => {}
+    }
+    match resolved {
+        TokenType::IsoYear => return token.possible_types.contains(&TokenType::Year4),
+        TokenType::IsoYear2 => return token.possible_types.contains(&TokenType::Year2),
+        TokenType::IsoWeek => return token.value.len() == 2 && token.numeric_value.is_some_and(|v| (1..=53).contains(&v)),
+        _ => {}
+    }
+    // Unpadded ordinal days ("2025-5") classify as day/month numbers
+    if *resolved == TokenType::DayOfYear {
+        return token.value.len() <= 3 && token.numeric_value.is_some_and(|v| (1..=366).contains(&v));
+    }
+    // DayOrMonth equivalence: if the resolved type is Day or Month,
+    // a token with DayOrMonth in its possible types is compatible
+    match resolved {
+        TokenType::Day | TokenType::Month | TokenType::DayOrMonth => {
+            token.possible_types.iter().any(|t| matches!(t,
+                TokenType::Day | TokenType::Month | TokenType::DayOrMonth
+            ))
+        }
+        _ => false,
+    }
+}
+
+/// The row shapes an inferred format accepts, checked by strict
+/// validation and by [`infer_and_partition_with_options`]
+#[derive(Debug, Default)]
+struct RowShapes {
+    /// The resolved types, then the optional-seconds and
+    /// optional-timezone shapes
+    types: Vec<Vec<TokenType>>,
+    /// Tokens carrying the majority spellings
+    template: Vec<Token>,
+}
+
+impl RowShapes {
+    /// Check a tokenized row against any of the shapes and the majority
+    /// spellings, and with `check_values` its value ranges.
+    fn accepts(&self, tokens: &[Token], check_values: bool) -> bool {
+        matches_spelling(tokens, &self.template)
+            && self
+                .types
+                .iter()
+                .any(|types| is_compatible(tokens, types) && (!check_values || has_valid_values(tokens, types)))
+    }
+}
+
+/// Check if a tokenized date is compatible with the resolved types.
+fn is_compatible(tokens: &[Token], resolved_types: &[TokenType]) -> bool {
+    if tokens.len() != resolved_types.len() {
+        return false;
+    }
+    tokens.iter().zip(resolved_types.iter()).all(|(token, resolved)| {
+        is_token_compatible(token, resolved)
+    })
+}
+
+/// Check that each numeric value falls in its resolved component's range
+/// and that the day exists in the date's month.
+fn has_valid_values(tokens: &[Token], resolved_types: &[TokenType]) -> bool {
+    let (mut day, mut month, mut year) = (None, None, None);
+    for (token, resolved) in tokens.iter().zip(resolved_types) {
+        let value = token.numeric_value;
+        let in_range = match (resolved, value) {
+            (TokenType::Day, Some(v)) => {
+                day = Some(v);
+                (1..=31).contains(&v)
+            }
+            (TokenType::Month, Some(v)) => {
+                month = Some(v);
+                (1..=12).contains(&v)
+            }
+            (TokenType::MonthName | TokenType::MonthNameShort, _) => {
+                month = constraints::month_number(&token.value);
+                true
+            }
+            (TokenType::Year4, Some(v)) => {
+                year = Some(v);
+                true
+            }
+            (TokenType::Year2, Some(v)) => {
+                year = Some(2000 + v);
+                true
+            }
+            // 24 only classifies as an hour in an allowed end-of-day time
+            (TokenType::Hour24, Some(v)) => v <= 23 || token.possible_types.contains(&TokenType::Hour24),
+            (TokenType::Hour12, Some(v)) => (1..=12).contains(&v),
+            (TokenType::Minute | TokenType::Second, Some(v)) => v <= 59,
+            _ => true,
+        };
+        if !in_range {
+            return false;
+        }
+    }
+    if let Some(pos) = resolved_types.iter().position(|t| *t == TokenType::DayOfYear) {
+        let days_in_year = 337 + constraints::days_in_month(2, year);
+        if !tokens[pos].numeric_value.is_some_and(|v| (1..=days_in_year).contains(&v)) {
+            return false;
+        }
+    }
+    match (day, month) {
+        (Some(d), Some(m)) => d <= constraints::days_in_month(m, year),
+        _ => true,
+    }
+}
+
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "arrow")]
+pub use arrow::{infer_utf8, infer_utf8_with_options};
+
+#[cfg(feature = "python")]
+#[allow(clippy::useless_conversion)]
+mod python;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unambiguous_dmy() {
+        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        assert!(result.confidence > 0.9);
+    }
+
+    #[test]
+    fn test_consensus_resolves_ambiguous() {
+        // 01/02 is ambiguous, but 15/03 proves it's DD/MM
+        let dates = vec!["01/02/2025", "15/03/2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+    }
+
+    #[test]
+    fn test_iso_format() {
+        let dates = vec!["2025-01-15", "2025-03-20"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d");
+    }
+
+    #[test]
+    fn test_month_name() {
+        let dates = vec!["15 Jan 2025", "20 Mar 2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d %b %Y");
+    }
+
+    #[test]
+    fn test_month_name_without_separators() {
+        let dates = vec!["12Jan2025", "05Mar2025", "1Feb2025"];
+        let options = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%d%b%Y");
+        assert_eq!(result.token_types, [TokenType::Day, TokenType::MonthNameShort, TokenType::Year4]);
+
+        assert_eq!(infer(&["12JAN25", "13MAR25"]).unwrap().format, "%d%b%y");
+        assert_eq!(infer(&["12Jan2025 10:30", "05Mar2025 14:45"]).unwrap().format, "%d%b%Y %H:%M");
+    }
+
+    #[test]
+    fn test_month_abbreviation_with_period() {
+        let dates = vec!["15 Jan. 2025", "20 Mar. 2025", "21 Apr. 2025"];
+        let options = InferOptions {
+            strict: true,
+            strict_values: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%d %b. %Y");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        // The majority spelling wins; the other rows cost confidence and
+        // fail strict validation
+        let dates = vec!["15 Jan. 2025", "20 Mar 2025", "21 Apr 2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d %b %Y");
+        assert!((result.confidence - (2.0 + 2.0 / 3.0) / 3.0).abs() < 1e-9);
+        assert!(matches!(
+            infer_with_options(&dates, &options),
+            Err(DateInferError::StrictValidationFailed { failed_count: 1, total_count: 3 })
+        ));
+
+        assert_eq!(infer(&["Jan. 15, 2025", "Mar. 20, 2025"]).unwrap().format, "%b. %d, %Y");
+        // A period between components stays a separator
+        assert_eq!(infer(&["15.Jan.2025", "20.Mar.2025"]).unwrap().format, "%d.%b.%Y");
+    }
+
+    #[test]
+    fn test_year_in_middle() {
+        let result = infer(&["03-2025-15", "04-2025-20"]).unwrap();
+        assert_eq!(result.format, "%m-%Y-%d");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        assert_eq!(infer(&["15-2025-03", "20-2025-04"]).unwrap().format, "%d-%Y-%m");
+        // Without proof either way the day-first preference applies
+        assert_eq!(infer(&["03-2025-01", "04-2025-02"]).unwrap().format, "%d-%Y-%m");
+    }
+
+    #[test]
+    fn test_leading_two_digit_year_above_31() {
+        // 99 and 45 can't be a day or month, so the first slot is the year
+        let result = infer(&["99/01/15", "45/02/20", "05/03/12"]).unwrap();
+        assert_eq!(result.format, "%y/%m/%d");
+        assert!(!result.used_preference);
+
+        assert_eq!(infer(&["99-12-01", "98-11-02"]).unwrap().format, "%y-%m-%d");
+        // 32-59 also fit a minute or second, but not a day or month
+        let result = infer(&["45/02/20", "50/03/12"]).unwrap();
+        assert_eq!(result.format, "%y/%m/%d");
+        assert!(!result.used_preference);
+        // A trailing year is unaffected
+        assert_eq!(infer(&["15/01/99", "20/02/98"]).unwrap().format, "%d/%m/%y");
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let dates: Vec<&str> = vec![];
+        let result = infer(&dates);
+        assert!(matches!(result, Err(DateInferError::EmptyInput)));
+    }
+
+    #[test]
+    fn test_two_day_only_positions_unresolvable() {
+        // 25 and 31 are both > 12, so neither slot can be the month
+        let result = infer(&["25/31/2025"]);
+        assert!(matches!(result, Err(DateInferError::UnresolvableAmbiguity)));
+
+        // Same conflict spread across rows
+        let result = infer(&["25/01/2025", "01/31/2025"]);
+        assert!(matches!(result, Err(DateInferError::UnresolvableAmbiguity)));
+    }
+
+    #[test]
+    fn test_proven_day_forces_month_complement() {
+        // Slot 0 never exceeds 12, but slot 1 does, so slot 0 is the month
+        let dates = vec!["06/25/2025", "06/13/2025"];
+        for prefer_dayfirst in [true, false] {
+            let options = InferOptions {
+                prefer_dayfirst,
+                ..Default::default()
+            };
+            let result = infer_with_options(&dates, &options).unwrap();
+            assert_eq!(result.format, "%m/%d/%Y");
+            assert!(!result.used_preference);
+        }
+    }
+
+    #[test]
+    fn test_prefer_dayfirst_false() {
+        // All ambiguous, rely on preference
+        let dates = vec!["01/02/2025", "03/04/2025"];
+        let options = InferOptions {
+            prefer_dayfirst: false,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%m/%d/%Y");
+    }
+
+    #[test]
+    fn test_single_date_ambiguous() {
+        // Single ambiguous date - uses rules + preference
+        let dates = vec!["01/02/2025"];
+        let result = infer(&dates).unwrap();
+        // With dayfirst=true (default), should be DD/MM/YYYY
+        assert_eq!(result.format, "%d/%m/%Y");
+    }
+
+    #[test]
+    fn test_single_date_unambiguous() {
+        // Single date with day > 12
+        let dates = vec!["25/12/2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+    }
+
+    #[test]
+    fn test_single_iso_with_offset() {
+        let result = infer(&["2025-01-15T10:30:00+05:30"]).unwrap();
+        assert_eq!(result.format, "%Y-%m-%dT%H:%M:%S%z");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+        assert_eq!(result.token_types.last(), Some(&TokenType::TzOffset));
+    }
+
+    #[test]
+    fn test_single_iso_ambiguous_day_is_not_swapped() {
+        // Both 01 and 05 are ambiguous; year-first means YYYY-MM-DD
+        let result = infer(&["2025-01-05T10:30:00+05:30"]).unwrap();
+        assert_eq!(result.format, "%Y-%m-%dT%H:%M:%S%z");
+        let result = infer(&["2025-01-05"]).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d");
+    }
+
+    #[test]
+    fn test_single_complex_datetimes() {
+        let cases = [
+            ("2025-01-15T10:30:00.123456Z", "%Y-%m-%dT%H:%M:%S.%fZ"),
+            ("2025-01-15T10:30:00.123-08:00", "%Y-%m-%dT%H:%M:%S.%f%z"),
+            ("2025-12-31 00:00:00 +0000", "%Y-%m-%d %H:%M:%S %z"),
+            ("Mon Jan 13 09:52:52 MST 2014", "%a %b %d %H:%M:%S %Z %Y"),
+            ("13 January 2025 02:30:00 PM", "%d %B %Y %I:%M:%S %p"),
+            ("15/03/2025 14:45:30", "%d/%m/%Y %H:%M:%S"),
+        ];
+        for (date, expected) in cases {
+            let result = infer(&[date]).unwrap();
+            assert_eq!(result.format, expected, "input: {}", date);
+            assert_eq!(result.confidence, 1.0, "input: {}", date);
+        }
+    }
+
+    #[test]
+    fn test_datetime_with_time() {
+        let dates = vec!["2025-01-15 10:30:00", "2025-03-20 14:45:30"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M:%S");
+    }
+
+    #[test]
+    fn test_time_only_trailing_second_not_year2() {
+        // Trailing values <= 99 also vote Year2; the time group must claim them
+        for dates in [["10:30:25"], ["00:00:59"]] {
+            let result = infer(&dates).unwrap();
+            assert_eq!(result.format, "%H:%M:%S");
+            assert_eq!(result.token_types[4], TokenType::Second);
+        }
+    }
+
+    #[test]
+    fn test_two_part_time_above_23_is_minutes_seconds() {
+        // Most first slots can't be an hour, so the pair is MM:SS
+        let result = infer(&["45:30", "52:10", "10:15"]).unwrap();
+        assert_eq!(result.format, "%M:%S");
+        assert_eq!(result.token_types[0], TokenType::Minute);
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        // A few out-of-range rows are dirty hours that cost confidence,
+        // bare or after a date
+        let mut times: Vec<String> = (0..99).map(|i| format!("{:02}:30", i % 24)).collect();
+        times.push("24:00".to_string());
+        let result = infer(&times).unwrap();
+        assert_eq!(result.format, "%H:%M");
+        assert!(result.confidence < 1.0);
+        let mut dates: Vec<String> = (0..99).map(|i| format!("2025-01-{:02} {:02}:30", i % 28 + 1, i % 24)).collect();
+        dates.push("2025-01-15 25:30".to_string());
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M");
+        assert!(result.confidence < 1.0);
+
+        // Without proof the pair stays HH:MM
+        assert_eq!(infer(&["10:30", "11:15"]).unwrap().format, "%H:%M");
+        // Three parts stay HH:MM:SS with the out-of-range hour lowering confidence
+        let result = infer(&["45:30:10", "10:15:00"]).unwrap();
+        assert_eq!(result.format, "%H:%M:%S");
+        assert!(result.confidence < 1.0);
+    }
+
+    #[test]
+    fn test_fraction_after_two_part_time() {
+        // A bare MM:SS.fff, as in lap times and media offsets
+        let result = infer(&["10:30.123", "11:45.456"]).unwrap();
+        assert_eq!(result.format, "%M:%S.%f");
+        assert_eq!(result.token_types[4], TokenType::Subsecond);
+
+        // After a date the pair keeps its hour; %f then parses the fraction
+        let result = infer(&["2025-01-15 10:30.123", "2025-01-16 11:45.456"]).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M.%f");
+        // A full time is unaffected
+        assert_eq!(infer(&["10:30:15.123", "11:45:00.456"]).unwrap().format, "%H:%M:%S.%f");
+    }
+
+    #[test]
+    fn test_time_words() {
+        let dates = vec!["March 3, 2025 at noon", "March 4, 2025 at midnight"];
+        let options = InferOptions {
+            allow_time_words: true,
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%B %d, %Y at %H:%M");
+        assert_eq!(result.token_types[9], TokenType::TimeWord);
+
+        // Off by default: the words stay literal text
+        let result = infer(&["March 3, 2025 at noon"]).unwrap();
+        assert_eq!(result.format, "%B %d, %Y at noon");
+    }
+
+    #[test]
+    fn test_hour_24_end_of_day() {
+        let dates = vec!["2025-01-15 24:00:00", "2025-01-16 10:30:00", "2025-01-17 00:00:00"];
+        let options = InferOptions {
+            allow_hour_24: true,
+            strict: true,
+            strict_values: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M:%S");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        // Off by default, 24 fails strict validation
+        let strict = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            infer_with_options(&dates, &strict),
+            Err(DateInferError::StrictValidationFailed { failed_count: 1, .. })
+        ));
+        // Only an all-zero time can start at 24
+        let result = infer_with_options(&["24:30:00", "10:15:00"], &options);
+        assert!(matches!(result, Err(DateInferError::StrictValidationFailed { failed_count: 1, .. })));
+
+        // Midnight as 00 needs no option
+        let result = infer_with_options(&["00:00:00", "23:59:59"], &strict).unwrap();
+        assert_eq!(result.format, "%H:%M:%S");
+        assert_eq!(result.token_types[0], TokenType::Hour24);
+    }
+
+    #[test]
+    fn test_fiscal_years() {
+        let options = InferOptions {
+            fiscal_years: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&["'25", "'24"], &options).unwrap();
+        assert_eq!(result.format, "'%y");
+        assert_eq!(result.token_types[1], TokenType::Year2);
+
+        let result = infer_with_options(&["Mar '25", "Jan '24"], &options).unwrap();
+        assert_eq!(result.format, "%b '%y");
+
+        let result = infer_with_options(&["FY2025", "FY2024"], &options).unwrap();
+        assert_eq!(result.format, "FY%Y");
+        let result = infer_with_options(&["FY25", "FY24"], &options).unwrap();
+        assert_eq!(result.format, "FY%y");
+    }
+
+    #[test]
+    fn test_full_month_name() {
+        let dates = vec!["15 January 2025", "20 March 2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d %B %Y");
+    }
+
+    #[test]
+    fn test_american_format() {
+        // American format with unambiguous month (13 can't be month)
+        let dates = vec!["12/13/2025", "01/25/2025"];
+        let result = infer(&dates).unwrap();
+        // 13 and 25 can't be months, so format must be MM/DD/YYYY
+        assert_eq!(result.format, "%m/%d/%Y");
+    }
+
+    #[test]
+    fn test_iso_with_t_separator() {
+        let dates = vec!["2025-01-15T10:30:00", "2025-03-20T14:45:30"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y-%m-%dT%H:%M:%S");
+    }
+
+    #[test]
+    fn test_year_range_excludes_historical_by_default() {
+        let dates = vec!["1776-07-04", "1789-04-30"];
+        let result = infer(&dates).unwrap();
+        assert_ne!(result.format, "%Y-%m-%d");
+    }
+
+    #[test]
+    fn test_year_range_widened() {
+        let dates = vec!["1776-07-04", "1789-04-30"];
+        let options = InferOptions {
+            year_range: 1700..=2100,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_year_range_future() {
+        let dates = vec!["15/03/2250", "20/04/2251"];
+        let options = InferOptions {
+            year_range: 1900..=2300,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+    }
+
+    #[test]
+    fn test_case_sensitive_accepts_canonical_names() {
+        let dates = vec!["15 Jan 2025", "20 Mar 2025"];
+        let options = InferOptions {
+            case_sensitive: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%d %b %Y");
+    }
+
+    #[test]
+    fn test_case_sensitive_rejects_lowercase_names() {
+        let options = InferOptions {
+            case_sensitive: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&["15 jan 2025", "20 jan 2025"], &options).unwrap();
+        assert!(!result.token_types.contains(&TokenType::MonthNameShort));
+
+        // Default matching is case-insensitive
+        let result = infer(&["15 jan 2025", "20 jan 2025"]).unwrap();
+        assert_eq!(result.format, "%d %b %Y");
+    }
+
+    #[test]
+    fn test_case_sensitive_strict_flags_lowercase_row() {
+        let dates = vec!["15 Jan 2025", "20 Mar 2025", "25 january 2025"];
+        let options = InferOptions {
+            case_sensitive: true,
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options);
+        assert!(matches!(
+            result,
+            Err(DateInferError::StrictValidationFailed { failed_count: 1, .. })
+        ));
+    }
+
+    // =========================================
+    // Real-world format tests
+    // =========================================
+
+    #[test]
+    fn test_dd_mmm_yyyy_dash() {
+        
+        let dates = vec!["26-May-2023", "01-Jul-2024", "02-Aug-2024"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d-%b-%Y");
+    }
+
+    #[test]
+    fn test_dd_mmm_yy_uppercase() {
+        // Abbreviated month, 2-digit year
+        let dates = vec!["29-AUG-24", "05-SEP-24", "06-SEP-24"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d-%b-%y");
+    }
+
+    #[test]
+    fn test_dd_mm_yy_slash() {
+        // 2-digit year, slash separator
+        let dates = vec!["10/06/24", "11/06/24", "12/06/24"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%y");
+    }
+
+    #[test]
+    fn test_dd_mm_yy_years_above_31() {
+        // Year values > 31 can only be Year2
+        let dates = vec!["10/06/40", "10/06/99", "11/07/55"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%y");
+        // The year is certain; the day/month order is the preference's guess
+        assert!(result.used_preference);
+        assert!((result.confidence - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_identical_ambiguous_dates() {
+        // Nothing tells day from month: the preference decides, every run,
+        // and the guessed positions halve their confidence
+        let dates = vec!["01/01/2025", "01/01/2025"];
+        for _ in 0..3 {
+            let result = infer(&dates).unwrap();
+            assert_eq!(result.format, "%d/%m/%Y");
+            assert!(result.used_preference);
+            assert!((result.confidence - 2.0 / 3.0).abs() < 1e-9);
+        }
+        let options = InferOptions {
+            prefer_dayfirst: false,
+            ..Default::default()
+        };
+        assert_eq!(infer_with_options(&dates, &options).unwrap().format, "%m/%d/%Y");
+        // Proof of the order restores full confidence
+        let result = infer(&["01/01/2025", "13/01/2025"]).unwrap();
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_dd_mm_yy_mixed_year_ranges() {
+        // Some years look like days (24, 31), others cannot (45, 99)
+        let dates = vec!["10/06/24", "11/06/45", "12/07/31", "01/02/99"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%y");
+        assert_eq!(result.token_types[4], TokenType::Year2);
+    }
+
+    #[test]
+    fn test_mm_dd_yy_years_above_31() {
+        let dates = vec!["06/13/40", "10/06/99"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%m/%d/%y");
+    }
+
+    #[test]
+    fn test_dd_mm_yy_with_dot_time() {
+        // Date with dot-separated time
+        let dates = vec!["10/06/24 12.25.10", "10/06/24 14.30.14", "12/06/24 19.55.14"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%y %H.%M.%S");
+    }
+
+    #[test]
+    fn test_dotted_iso_date() {
+        let dates = vec!["2025.01.15", "2025.03.20", "2025.12.01"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y.%m.%d");
+    }
+
+    #[test]
+    fn test_dotted_european_date() {
+        let dates = vec!["15.03.2025", "20.04.2025", "01.02.2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d.%m.%Y");
+    }
+
+    #[test]
+    fn test_dotted_date_after_weekday_is_not_time() {
+        // The space after the weekday is not a date/time boundary
+        let dates = vec!["Mon 15.03.2025", "Tue 20.04.2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%a %d.%m.%Y");
+        let result = infer(&["Sat 2025.01.15"]).unwrap();
+        assert_eq!(result.format, "%a %Y.%m.%d");
+    }
+
+    #[test]
+    fn test_dotted_date_and_dotted_time() {
+        let dates = vec!["2025.01.15 10.30.00", "2025.03.20 14.45.30"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y.%m.%d %H.%M.%S");
+    }
+
+    #[test]
+    fn test_european_dotted_date_and_time() {
+        // The time's dots mirror the date's
+        let dates = vec!["15.03.2025 14.30.05", "20.04.2025 09.15.45"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d.%m.%Y %H.%M.%S");
+        let result = infer(&["15.03.2025", "20.04.2025"]).unwrap();
+        assert_eq!(result.format, "%d.%m.%Y");
+    }
+
+    #[test]
+    fn test_dotted_time_before_dotted_date() {
+        let dates = vec!["14.30.05 15.03.2025", "09.15.45 20.04.2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%H.%M.%S %d.%m.%Y");
+
+        // A leading two-part group stays a date
+        let result = infer(&["15.03 2025", "20.04 2025"]).unwrap();
+        assert_eq!(result.format, "%d.%m %Y");
+    }
+
+    #[test]
+    fn test_mixed_separators_use_majority() {
+        let dates = vec!["15-03-2025", "15/03/2025", "20/04/2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        assert!(result.confidence < 1.0);
+
+        // Consistent separators keep full confidence
+        let result = infer(&["15/03/2025", "20/04/2025"]).unwrap();
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        let explanation = explain_position(&dates, &InferOptions::default(), 1).unwrap().unwrap();
+        let confidence = explanation.confidence.unwrap();
+        assert!((confidence - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mixed_date_time_separator_uses_majority() {
+        // The first example's space is outvoted by the T of the others
+        let dates = vec!["2025-01-15 10:30:00", "2025-01-15T10:30:00", "2025-03-20T14:45:30"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y-%m-%dT%H:%M:%S");
+        assert!(result.confidence < 1.0);
+
+        let dates = vec!["2025-01-15 10:30:00", "2025-03-20 14:45:30", "2025-01-15T10:30:00"];
+        assert_eq!(infer(&dates).unwrap().format, "%Y-%m-%d %H:%M:%S");
+
+        let explanation = explain_position(&dates, &InferOptions::default(), 5).unwrap().unwrap();
+        assert_eq!(explanation.resolved, TokenType::Separator(' '));
+        assert!((explanation.confidence.unwrap() - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_doubled_separators() {
+        let dates = vec!["15//03//2025", "20//04//2025", "2025--01--15"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d//%m//%Y");
+        assert_eq!(result.token_types.len(), 5);
+
+        assert_eq!(infer(&["2025--01--15", "2025--03--20"]).unwrap().format, "%Y--%m--%d");
+
+        // Mixed widths take the majority; the other rows cost confidence
+        // and fail strict validation
+        let dates = vec!["15/03/2025", "20//04//2025", "21//05//2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d//%m//%Y");
+        assert!((result.confidence - (3.0 + 4.0 / 3.0) / 5.0).abs() < 1e-9);
+        let strict = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            infer_with_options(&dates, &strict),
+            Err(DateInferError::StrictValidationFailed { failed_count: 1, total_count: 3 })
+        ));
+        // Runs of spaces read alike
+        assert!((infer(&["Jan  5 2025", "Jan 15 2025"]).unwrap().confidence - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mixed_year_widths_use_majority() {
+        let dates = vec!["15/03/25", "16/03/2025", "17/03/2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        let explanation = explain_position(&dates, &InferOptions::default(), 4).unwrap().unwrap();
+        assert!((explanation.confidence.unwrap() - 2.0 / 3.0).abs() < 1e-9);
+
+        let result = infer(&["15/03/25", "16/03/25", "17/03/2025"]).unwrap();
+        assert_eq!(result.format, "%d/%m/%y");
+        assert!(result.confidence < 1.0);
+
+        // A year-first outlier doesn't make the day slot a year
+        let result = infer(&["15/03/2025", "20/04/2025", "2025-01-15"]).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+    }
+
+    #[test]
+    fn test_mon_dd_comma_yyyy() {
+        // Month-first with comma: Mon DD, YYYY
+        let dates = vec!["Dec 17, 2024", "Dec 18, 2024", "Jan 24, 2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%b %d, %Y");
+    }
+
+    #[test]
+    fn test_bracketed_log_timestamps() {
+        let dates = vec!["[2025-01-15 10:30:00]", "[2025-03-20 14:45:30]"];
+        let options = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M:%S");
+    }
+
+    #[test]
+    fn test_quoted_dates() {
+        let dates = vec!["\"15/03/2025\"", "\"01/02/2025\"", "'20/04/2025'"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_weekday_numbers() {
+        let dates = vec!["2025-01-15 3", "2025-01-16 4", "2025-01-19 7"];
+        // Without the hint the trailing digit reads as an hour
+        assert_eq!(infer(&dates).unwrap().format, "%Y-%m-%d %H");
+
+        let options = InferOptions {
+            weekday_numbers: true,
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %u");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        // A Sunday written as 0 switches to %w
+        let dates = vec!["2025-01-15 3", "2025-01-19 0"];
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %w");
+        assert_eq!(result.token_types[6], TokenType::WeekdayNumberFromSunday);
+    }
+
+    #[test]
+    fn test_iso_week() {
+        let dates = vec!["2025-W03", "2025-W14", "2024-W52"];
+        let options = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%G-W%V");
+        assert!(!result.format.contains("%Y"));
+        assert_eq!(result.token_types[0], TokenType::IsoYear);
+        assert_eq!(result.token_types[3], TokenType::IsoWeek);
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        assert_eq!(infer(&["2025W03", "2025W53"]).unwrap().format, "%GW%V");
+
+        // With a weekday it is the full week date
+        let result = infer(&["2025-W03-1", "2025-W14-7"]).unwrap();
+        assert_eq!(result.format, "%G-W%V-%u");
+    }
+
+    #[test]
+    fn test_iso_week_two_digit_year() {
+        let dates = vec!["25-W03", "25-W14", "24-W52"];
+        let options = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%g-W%V");
+        assert!(!result.format.contains("%y"));
+        assert_eq!(result.token_types[0], TokenType::IsoYear2);
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unicode_digits() {
+        let dates = vec!["٢٠٢٥-٠١-١٥", "٢٠٢٥-٠٣-٢٠"];
+        let options = InferOptions {
+            unicode_digits: true,
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+        assert!(infer(&dates).is_err());
+    }
+
+    #[test]
+    fn test_japanese_date_markers() {
+        let dates = vec!["2025年01月15日", "2025年03月02日", "2024年12月31日"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y年%m月%d日");
+        assert_eq!(result.token_types[1], TokenType::Separator('年'));
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ordinal_date_padded() {
+        let dates = vec!["2025-005", "2025-120", "2024-366"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y-%j");
+        assert_eq!(result.token_types[2], TokenType::DayOfYear);
+
+        // Padded input keeps %j with padding detection on
+        let options = InferOptions {
+            detect_padding: true,
+            ..Default::default()
+        };
+        assert_eq!(infer_with_options(&dates, &options).unwrap().format, "%Y-%j");
+    }
+
+    #[test]
+    fn test_ordinal_date_unpadded() {
+        let dates = vec!["2025-5", "2025-45", "2025-120"];
+        let options = InferOptions {
+            detect_padding: true,
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%-j");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        assert_eq!(infer(&dates).unwrap().format, "%Y-%j");
+    }
+
+    #[test]
+    fn test_ordinal_day_out_of_range_for_year() {
+        let options = InferOptions {
+            strict: true,
+            strict_values: true,
+            ..Default::default()
+        };
+        assert!(infer_with_options(&["2024-366", "2024-001"], &options).is_ok());
+        assert!(infer_with_options(&["2025-366", "2025-001"], &options).is_err());
+    }
+
+    #[test]
+    fn test_non_padded_mdy() {
+        // Non-zero-padded M/D/YYYY
+        let dates = vec!["5/1/2024", "5/2/2024", "12/15/2024"];
+        let result = infer(&dates).unwrap();
+        // Should detect as MM/DD/YYYY because 15 > 12
+        assert_eq!(result.format, "%m/%d/%Y");
+
+        let options = InferOptions {
+            detect_padding: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%-m/%-d/%Y");
+    }
+
+    #[test]
+    fn test_spreadsheet_stripped_zeros() {
+        // Excel drops leading zeros on some rows but not others
+        let dates = vec!["1/2/2025", "15/12/2025", "03/04/2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        let options = InferOptions {
+            detect_padding: true,
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%-d/%-m/%Y");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mixed_full_and_short_month_names() {
+        // Majority full names: %B, with confidence reduced by the odd row
+        let result = infer(&["15 January 2025", "16 Feb 2025", "17 March 2025"]).unwrap();
+        assert_eq!(result.format, "%d %B %Y");
+        assert!(result.confidence < 1.0);
+
+        // Majority short names: %b
+        let result = infer(&["15 Jan 2025", "16 February 2025", "17 Mar 2025"]).unwrap();
+        assert_eq!(result.format, "%d %b %Y");
+
+        // "May" counts as both, so it never breaks strict mode
+        let options = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&["15 January 2025", "16 May 2025"], &options).unwrap();
+        assert_eq!(result.format, "%d %B %Y");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
     }
 
     #[test]
-    fn test_iso_format() {
-        let dates = vec!["2025-01-15", "2025-03-20"];
+    fn test_month_year_only() {
+        // Full month name, comma, year (no day)
+        let dates = vec!["December, 2024", "January, 2025", "February, 2025"];
         let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%Y-%m-%d");
+        assert_eq!(result.format, "%B, %Y");
     }
 
     #[test]
-    fn test_month_name() {
-        let dates = vec!["15 Jan 2025", "20 Mar 2025"];
+    fn test_year_month_iso() {
+        let result = infer(&["2025-01", "2025-03", "2025-12"]).unwrap();
+        assert_eq!(result.format, "%Y-%m");
+        assert_eq!(result.token_types[2], TokenType::Month);
+
+        let result = infer(&["2025/07", "2025/08"]).unwrap();
+        assert_eq!(result.format, "%Y/%m");
+    }
+
+    #[test]
+    fn test_month_year_numeric() {
+        // The lone slot next to a year is the month, even with prefer_dayfirst
+        let result = infer(&["01/2025", "07/2025"]).unwrap();
+        assert_eq!(result.format, "%m/%Y");
+    }
+
+    #[test]
+    fn test_dd_mmm_no_year() {
+        // Day/abbreviated month, no year
+        let dates = vec!["31/OCT", "01/NOV", "04/NOV"];
         let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d %b %Y");
+        assert_eq!(result.format, "%d/%b");
     }
 
+    // =========================================
+    // InconsistentFormats tolerance tests
+    // =========================================
+
     #[test]
-    fn test_empty_input() {
-        let dates: Vec<&str> = vec![];
+    fn test_trailing_space_tolerated() {
+        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025 "];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+    }
+
+    #[test]
+    fn test_one_malformed_row_tolerated() {
+        // 4 good dates + 1 "N/A" → succeeds with reduced confidence
+        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025", "01/01/2025", "N/A"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        assert!(result.confidence < 1.0);
+    }
+
+    #[test]
+    fn test_empty_string_tolerated() {
+        // good dates + one "" → succeeds
+        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025", ""];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+    }
+
+    #[test]
+    fn test_inconsistent_formats_reports_length_counts() {
+        let dates = vec!["15/03/2025", "20/04/2025", "2025-01-15T10:30:00", "Jan 2025", "Feb 2025"];
+        let Err(DateInferError::InconsistentFormats { length_counts }) = infer(&dates) else {
+            panic!("expected InconsistentFormats");
+        };
+        assert_eq!(length_counts, BTreeMap::from([(3, 2), (5, 2), (11, 1)]));
+    }
+
+    #[test]
+    fn test_inconsistent_formats_when_no_majority() {
+        // Truly mixed token counts with no >50% majority → still errors
+        let dates = vec!["15/03/2025", "2025-01-15T10:30:00", "Jan 2025"];
         let result = infer(&dates);
-        assert!(matches!(result, Err(DateInferError::EmptyInput)));
+        assert!(matches!(result, Err(DateInferError::InconsistentFormats { .. })));
     }
 
     #[test]
-    fn test_prefer_dayfirst_false() {
-        // All ambiguous, rely on preference
-        let dates = vec!["01/02/2025", "03/04/2025"];
+    fn test_confidence_reflects_filtered_proportion() {
+        // 4 good dates + 1 bad → confidence reduced proportionally
+        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025", "01/06/2025", "N/A"];
+        let result = infer(&dates).unwrap();
+        // filter_ratio = 4/5 = 0.8, so confidence should be at most 0.8
+        assert!(result.confidence <= 0.8 + f64::EPSILON);
+        assert!(result.confidence > 0.0);
+    }
+
+    #[test]
+    fn test_detect_duration() {
+        let durations = vec!["P1Y2M10DT2H30M", "PT45S", "P3D", "2025-01-15"];
         let options = InferOptions {
-            prefer_dayfirst: false,
+            detect_duration: true,
             ..Default::default()
         };
-        let result = infer_with_options(&dates, &options).unwrap();
-        assert_eq!(result.format, "%m/%d/%Y");
+        let result = infer_with_options(&durations, &options);
+        assert!(matches!(
+            result,
+            Err(DateInferError::LooksLikeDuration { duration_count: 3, total_count: 4 })
+        ));
+
+        // Dates are unaffected by the check
+        let dates = vec!["2025-01-15", "2025-03-20"];
+        assert_eq!(infer_with_options(&dates, &options).unwrap().format, "%Y-%m-%d");
     }
 
     #[test]
-    fn test_single_date_ambiguous() {
-        // Single ambiguous date - uses rules + preference
-        let dates = vec!["01/02/2025"];
+    fn test_unknown_policy() {
+        let dates = vec!["15/03/2025 foo", "20/04/2025 bar"];
         let result = infer(&dates).unwrap();
-        // With dayfirst=true (default), should be DD/MM/YYYY
+        assert_eq!(result.format, "%d/%m/%Y foo");
+        assert_eq!(result.token_types[6], TokenType::Unknown);
+
+        let options = |unknown_policy| InferOptions {
+            unknown_policy,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options(UnknownPolicy::Wildcard)).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y %?");
+        assert_eq!(result.to_regex(), r"^(\d{1,2})/(\d{1,2})/(\d{4}) (.+?)$");
+
+        assert!(matches!(
+            infer_with_options(&dates, &options(UnknownPolicy::Error)),
+            Err(DateInferError::NoValidPattern)
+        ));
+        // Dates without unknown tokens are unaffected
+        let clean = vec!["15/03/2025", "20/04/2025"];
+        for policy in [UnknownPolicy::Literal, UnknownPolicy::Wildcard, UnknownPolicy::Error] {
+            assert_eq!(infer_with_options(&clean, &options(policy)).unwrap().format, "%d/%m/%Y");
+        }
+    }
+
+    #[test]
+    fn test_literal_connector_words() {
+        // A constant connector is kept verbatim at full confidence
+        let result = infer(&["15 March at 2025", "16 April at 2025", "17 May at 2025"]).unwrap();
+        assert_eq!(result.format, "%d %B at %Y");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        // A varying one still emits the first row's word, but only a third
+        // of the rows share it
+        let result = infer(&["15 March at 2025", "16 April on 2025", "17 May by 2025"]).unwrap();
+        assert_eq!(result.format, "%d %B at %Y");
+        assert!((result.confidence - (3.0 + 1.0 / 3.0) / 4.0).abs() < 1e-9);
+
+        // A wildcard matches any word, so the rows still agree
+        let options = InferOptions {
+            unknown_policy: UnknownPolicy::Wildcard,
+            ..Default::default()
+        };
+        let result = infer_with_options(&["15 March at 2025", "16 April on 2025"], &options).unwrap();
+        assert_eq!(result.format, "%d %B %? %Y");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_require_date_and_time() {
+        let require_date = InferOptions {
+            require_date: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&["10:30:00", "14:45:30"], &require_date);
+        assert!(matches!(
+            result,
+            Err(DateInferError::MissingComponent { component: "date", format }) if format == "%H:%M:%S"
+        ));
+        assert!(infer_with_options(&["2025-01-15 10:30", "2025-03-20 14:45"], &require_date).is_ok());
+
+        let require_time = InferOptions {
+            require_time: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&["2025-01-15", "2025-03-20"], &require_time);
+        assert!(matches!(result, Err(DateInferError::MissingComponent { component: "time", .. })));
+        assert!(infer_with_options(&["10:30:00", "14:45:30"], &require_time).is_ok());
+
+        let tokenized = vec![tokenize("10:30").unwrap()];
+        assert!(matches!(
+            infer_from_tokens(&tokenized, &require_date),
+            Err(DateInferError::MissingComponent { component: "date", .. })
+        ));
+    }
+
+    #[test]
+    fn test_detect_epoch() {
+        let epochs = vec!["1_705_312_200", "1705398600", "1.7053122e9", "2025-01-15"];
+        let options = InferOptions {
+            detect_epoch: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&epochs, &options);
+        assert!(matches!(
+            result,
+            Err(DateInferError::LooksLikeEpoch { epoch_count: 3, total_count: 4 })
+        ));
+
+        // Underscore-separated dates and compact dates are not epochs
+        let dates = vec!["2025_01_15", "2025_03_20"];
+        assert_eq!(infer_with_options(&dates, &options).unwrap().format, "%Y_%m_%d");
+        let dates = vec!["20250115", "20250320"];
+        assert_eq!(infer_with_options(&dates, &options).unwrap().format, "%Y%m%d");
+    }
+
+    #[test]
+    fn test_detect_season() {
+        let options = InferOptions {
+            detect_season: true,
+            ..Default::default()
+        };
+        let seasons = vec!["Spring 2025", "Fall 2024", "Q2 2025"];
+        assert!(matches!(
+            infer_with_options(&seasons, &options),
+            Err(DateInferError::LooksLikeSeason { season_count: 3, total_count: 3 })
+        ));
+
+        // Month names are not seasons
+        let months = vec!["March 2025", "April 2024"];
+        assert_eq!(infer_with_options(&months, &options).unwrap().format, "%B %Y");
+    }
+
+    #[test]
+    fn test_nothing_tokenizes() {
+        for dates in [vec!["", "", ""], vec!["#@!", "***", "  "]] {
+            let result = infer(&dates);
+            assert!(matches!(result, Err(DateInferError::NoDateTokens { input_count: 3 })));
+        }
+    }
+
+    // =========================================
+    // Best-effort tests
+    // =========================================
+
+    #[test]
+    fn test_best_effort_messy_dataset() {
+        // No structure has a majority, so infer() errors
+        let dates = vec![
+            "15/03/2025",
+            "2025-01-15T10:30:00",
+            "Jan 2025",
+            "20/04/2025",
+            "N/A",
+        ];
+        assert!(matches!(infer(&dates), Err(DateInferError::InconsistentFormats { .. })));
+
+        let result = infer_best_effort(&dates);
         assert_eq!(result.format, "%d/%m/%Y");
+        // 2 of 5 rows belong to the winning cluster
+        assert!((result.confidence - 0.4).abs() < 1e-9);
     }
 
     #[test]
-    fn test_single_date_unambiguous() {
-        // Single date with day > 12
-        let dates = vec!["25/12/2025"];
-        let result = infer(&dates).unwrap();
+    fn test_best_effort_matches_infer_on_clean_data() {
+        let dates = vec!["15/03/2025", "20/04/2025", "01/02/2025"];
+        let strict = infer(&dates).unwrap();
+        let best = infer_best_effort(&dates);
+        assert_eq!(best.format, strict.format);
+        assert_eq!(best.confidence, strict.confidence);
+    }
+
+    #[test]
+    fn test_best_effort_ignores_min_confidence() {
+        let dates = vec!["15/03/2025", "20/04/2025", "N/A"];
+        let options = InferOptions {
+            min_confidence: 0.99,
+            ..Default::default()
+        };
+        assert!(infer_with_options(&dates, &options).is_err());
+        let result = infer_best_effort_with_options(&dates, &options);
         assert_eq!(result.format, "%d/%m/%Y");
+        assert!(result.confidence < 0.99);
     }
 
     #[test]
-    fn test_datetime_with_time() {
-        let dates = vec!["2025-01-15 10:30:00", "2025-03-20 14:45:30"];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%Y-%m-%d %H:%M:%S");
+    fn test_best_effort_drops_rejecting_options() {
+        let dates = vec!["2025-01-15", "2025-03-20"];
+        for options in [
+            InferOptions {
+                pinned: vec![(9, TokenType::Day)],
+                ..Default::default()
+            },
+            InferOptions {
+                require_time: true,
+                ..Default::default()
+            },
+        ] {
+            assert!(infer_with_options(&dates, &options).is_err());
+            let result = infer_best_effort_with_options(&dates, &options);
+            assert_eq!(result.format, "%Y-%m-%d");
+            assert!((result.confidence - 1.0).abs() < 1e-9);
+        }
+
+        // Options that hold are still applied
+        let options = InferOptions {
+            pinned: vec![(2, TokenType::Day), (4, TokenType::Month)],
+            ..Default::default()
+        };
+        assert_eq!(infer_best_effort_with_options(&["2025-01-02"], &options).format, "%Y-%d-%m");
     }
 
     #[test]
-    fn test_full_month_name() {
-        let dates = vec!["15 January 2025", "20 March 2025"];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d %B %Y");
+    fn test_best_effort_empty_and_unparseable() {
+        let empty: Vec<&str> = vec![];
+        let result = infer_best_effort(&empty);
+        assert_eq!(result.format, "");
+        assert_eq!(result.confidence, 0.0);
+        assert!(result.token_types.is_empty());
+
+        let result = infer_best_effort(&["", "!!!"]);
+        assert_eq!(result.format, "");
+        assert_eq!(result.confidence, 0.0);
+    }
+
+    // =========================================
+    // Merge tests
+    // =========================================
+
+    #[test]
+    fn test_merge_compatible_shards() {
+        let a = infer(&["15/03/2025", "20/04/2025"]).unwrap();
+        let b = infer(&["01/02/2025", "25/12/2025", "N/A"]).unwrap();
+        let merged = InferResult::merge(&[a.clone(), b.clone()]).unwrap();
+        assert_eq!(merged.format, "%d/%m/%Y");
+        assert_eq!(merged.token_types, a.token_types);
+        let expected = (a.confidence + b.confidence) / 2.0;
+        assert!((merged.confidence - expected).abs() < 1e-9);
     }
 
     #[test]
-    fn test_american_format() {
-        // American format with unambiguous month (13 can't be month)
-        let dates = vec!["12/13/2025", "01/25/2025"];
-        let result = infer(&dates).unwrap();
-        // 13 and 25 can't be months, so format must be MM/DD/YYYY
-        assert_eq!(result.format, "%m/%d/%Y");
+    fn test_merge_incompatible_shards() {
+        let a = infer(&["15/03/2025", "20/04/2025"]).unwrap();
+        let b = infer(&["2025-01-15", "2025-03-20"]).unwrap();
+        let merged = InferResult::merge(&[a, b]);
+        assert!(matches!(merged, Err(DateInferError::InconsistentFormats { .. })));
     }
 
     #[test]
-    fn test_iso_with_t_separator() {
-        let dates = vec!["2025-01-15T10:30:00", "2025-03-20T14:45:30"];
-        let result = infer(&dates).unwrap();
+    fn test_merge_conflicting_day_month_order() {
+        let a = infer(&["15/03/2025"]).unwrap();
+        let b = infer(&["03/15/2025"]).unwrap();
+        assert!(InferResult::merge(&[a, b]).is_err());
+    }
+
+    #[test]
+    fn test_merge_empty() {
+        assert!(matches!(InferResult::merge(&[]), Err(DateInferError::EmptyInput)));
+    }
+
+    #[test]
+    fn test_canonicalize_dotted_to_dashes() {
+        let result = infer(&["15.03.2025", "20.04.2025"]).unwrap();
+        assert_eq!(result.format, "%d.%m.%Y");
+        assert_eq!(result.canonicalize('-'), "%d-%m-%Y");
+    }
+
+    #[test]
+    fn test_extract_from_key_value_logs() {
+        let dates = vec![
+            "ts=2025-01-15T10:30:00 level=info msg=started",
+            "ts=2025-03-20T14:45:30 level=warn msg=slow",
+            "ts=2025-06-01T08:00:00 level=info",
+        ];
+        let options = InferOptions {
+            extract: true,
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
         assert_eq!(result.format, "%Y-%m-%dT%H:%M:%S");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        assert_eq!(extract_date("[INFO] 15 Mar 2025 at noon", &options), "15 Mar 2025");
+        assert_eq!(extract_date("no date here", &options), "no date here");
+        assert_eq!(extract_date("2025年03月15日 起", &options), "2025年03月15日");
+    }
+
+    #[test]
+    fn test_extract_past_u16_positions() {
+        let options = InferOptions {
+            extract: true,
+            ..Default::default()
+        };
+        // Token positions wrap inside the date
+        let line = format!("{} 2025-01-15T10:30:00 end", "x".repeat(65_530));
+        assert_eq!(extract_date(&line, &options), "2025-01-15T10:30:00");
+        let line = format!("{} 2025-01-15 end", "x".repeat(70_000));
+        assert_eq!(extract_date(&line, &options), "2025-01-15");
+    }
+
+    #[test]
+    fn test_format_for_flavors() {
+        let options = InferOptions {
+            detect_padding: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&["5/03/2025", "20/04/2025"], &options).unwrap();
+        assert_eq!(result.format_for(FormatFlavor::Posix), "%-d/%m/%Y");
+        assert_eq!(result.format_for(FormatFlavor::PythonStrptime), "%d/%m/%Y");
+        assert_eq!(result.format_for(FormatFlavor::WindowsStrftime), "%#d/%m/%Y");
+    }
+
+    #[test]
+    fn test_used_preference() {
+        // A day > 12 proves the order
+        assert!(!infer(&["15/03/2025", "01/02/2025"]).unwrap().used_preference);
+        assert!(infer(&["01/02/2025", "03/04/2025"]).unwrap().used_preference);
+        let options = InferOptions {
+            prefer_dayfirst: false,
+            ..Default::default()
+        };
+        assert!(infer_with_options(&["01/02/2025", "03/04/2025"], &options).unwrap().used_preference);
+
+        // Year-first order is a convention, not a preference
+        assert!(!infer(&["2025-01-02", "2025-03-04"]).unwrap().used_preference);
+        assert!(!infer(&["15 Mar 2025"]).unwrap().used_preference);
+    }
+
+    #[test]
+    fn test_locale_composites() {
+        let dates = vec!["03/15/25 10:30:00", "04/20/25 14:45:30"];
+        let options = InferOptions {
+            locale_composites: true,
+            ..Default::default()
+        };
+        assert_eq!(infer_with_options(&dates, &options).unwrap().format, "%x %X");
+        assert_eq!(infer_with_options(&["03/15/25", "04/20/25"], &options).unwrap().format, "%x");
+        assert_eq!(infer(&dates).unwrap().format, "%m/%d/%y %H:%M:%S");
+
+        // Day-first dates have no composite
+        assert_eq!(infer_with_options(&["15/03/25"], &options).unwrap().format, "%d/%m/%y");
+    }
+
+    #[test]
+    fn test_describe_datetime() {
+        let result = infer(&["2025-01-15 10:30:00", "2025-03-20 14:45:30"]).unwrap();
+        assert_eq!(
+            result.describe(),
+            "4-digit year-month-day, dash-separated, hour:minute:second, colon-separated"
+        );
+
+        let result = infer(&["Mar 15, 2025 3:30 PM", "Apr 20, 2025 4:45 AM"]).unwrap();
+        assert_eq!(
+            result.describe(),
+            "abbreviated month name, day, 4-digit year, 12-hour clock hour:minute, colon-separated, AM/PM"
+        );
     }
 
     // =========================================
-    // Real-world format tests
+    // Null skipping tests
     // =========================================
 
     #[test]
-    fn test_dd_mmm_yyyy_dash() {
-        
-        let dates = vec!["26-May-2023", "01-Jul-2024", "02-Aug-2024"];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d-%b-%Y");
+    fn test_skip_nulls_counts_markers() {
+        let dates = vec!["15/03/2025", "", "N/A", "20/04/2025", "null", "NaN", "25/12/2025", "garbage"];
+        let options = InferOptions {
+            skip_nulls: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        assert_eq!(result.skipped_count, 4);
+        // Only "garbage" counts against confidence: 3 of 4 remaining rows
+        assert!((result.confidence - 0.75).abs() < 1e-9);
     }
 
     #[test]
-    fn test_dd_mmm_yy_uppercase() {
-        // Abbreviated month, 2-digit year
-        let dates = vec!["29-AUG-24", "05-SEP-24", "06-SEP-24"];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d-%b-%y");
+    fn test_skip_nulls_case_and_whitespace() {
+        let dates = vec!["15/03/2025", "  ", " n/a ", "NULL", "20/04/2025"];
+        let options = InferOptions {
+            skip_nulls: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.skipped_count, 3);
+        assert!((result.confidence - 1.0).abs() < 1e-9);
     }
 
     #[test]
-    fn test_dd_mm_yy_slash() {
-        // 2-digit year, slash separator
-        let dates = vec!["10/06/24", "11/06/24", "12/06/24"];
+    fn test_skip_nulls_disabled_by_default() {
+        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025", "N/A"];
         let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d/%m/%y");
+        assert_eq!(result.skipped_count, 0);
+        assert!(result.confidence < 1.0);
     }
 
     #[test]
-    fn test_dd_mm_yy_with_dot_time() {
-        // Date with dot-separated time
-        let dates = vec!["10/06/24 12.25.10", "10/06/24 14.30.14", "12/06/24 19.55.14"];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d/%m/%y %H.%M.%S");
+    fn test_skip_nulls_all_null() {
+        let dates = vec!["", "N/A", "null"];
+        let options = InferOptions {
+            skip_nulls: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options);
+        assert!(matches!(result, Err(DateInferError::EmptyInput)));
     }
 
     #[test]
-    fn test_mon_dd_comma_yyyy() {
-        // Month-first with comma: Mon DD, YYYY
-        let dates = vec!["Dec 17, 2024", "Dec 18, 2024", "Jan 24, 2025"];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%b %d, %Y");
-    }
+    fn test_custom_null_values() {
+        let dates = vec!["15/03/2025", "--", " ??? ", "20/04/2025", "N/A"];
+        let options = InferOptions {
+            null_values: vec!["--".to_string(), "???".to_string()],
+            strict: true,
+            ..Default::default()
+        };
+        // The defaults stay off without skip_nulls, so N/A fails strict
+        assert!(matches!(
+            infer_with_options(&dates, &options),
+            Err(DateInferError::StrictValidationFailed { failed_count: 1, .. })
+        ));
 
-    #[test]
-    fn test_non_padded_mdy() {
-        // Non-zero-padded M/D/YYYY
-        let dates = vec!["5/1/2024", "5/2/2024", "12/15/2024"];
-        let result = infer(&dates).unwrap();
-        // Should detect as MM/DD/YYYY because 15 > 12
-        assert_eq!(result.format, "%m/%d/%Y");
+        let options = InferOptions {
+            skip_nulls: true,
+            ..options
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        assert_eq!(result.skipped_count, 3);
+        assert!((result.confidence - 1.0).abs() < 1e-9);
     }
 
     #[test]
-    fn test_month_year_only() {
-        // Full month name, comma, year (no day)
-        let dates = vec!["December, 2024", "January, 2025", "February, 2025"];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%B, %Y");
+    fn test_length_filter_skips_prose() {
+        let dates = vec![
+            "15/03/2025",
+            "Payment was received after the invoice reminder was sent twice",
+            "20/04/2025",
+            "x",
+            "25/12/2025",
+        ];
+        let options = InferOptions {
+            length_filter: Some(DEFAULT_LENGTH_FILTER),
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        assert_eq!(result.skipped_count, 2);
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        // Combined with null skipping, both kinds are counted
+        let options = InferOptions {
+            skip_nulls: true,
+            length_filter: Some(DEFAULT_LENGTH_FILTER),
+            ..Default::default()
+        };
+        let mut with_null = dates.clone();
+        with_null.push("N/A");
+        let result = infer_with_options(&with_null, &options).unwrap();
+        assert_eq!(result.skipped_count, 3);
     }
 
+    // =========================================
+    // Context reuse tests
+    // =========================================
+
     #[test]
-    fn test_dd_mmm_no_year() {
-        // Day/abbreviated month, no year
-        let dates = vec!["31/OCT", "01/NOV", "04/NOV"];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d/%b");
+    fn test_context_matches_infer() {
+        let columns: Vec<Vec<&str>> = vec![
+            vec!["15/03/2025", "01/02/2025", "garbage", "20/04/2025"],
+            vec!["2025-01-15T10:30:00", "2025-03-20T14:45:00"],
+            vec!["01/02/2025", "03/04/2025"],
+            vec!["Mon Jan 13 09:52:52 MST 2014", "Tue Feb 14 10:00:00 MST 2014", "x", "y", "z"],
+            vec!["15 Mar 2025", "20 Apr 2025"],
+        ];
+        let options = InferOptions::default();
+        let mut ctx = InferContext::new();
+        for dates in &columns {
+            let expected = infer_with_options(dates, &options);
+            let actual = infer_with_context(&mut ctx, dates, &options);
+            match (expected, actual) {
+                (Ok(expected), Ok(actual)) => {
+                    assert_eq!(actual.format, expected.format);
+                    assert_eq!(actual.token_types, expected.token_types);
+                    assert_eq!(actual.confidence, expected.confidence);
+                }
+                (Err(expected), Err(actual)) => assert_eq!(actual.to_string(), expected.to_string()),
+                (expected, actual) => panic!("{expected:?} != {actual:?}"),
+            }
+        }
+
+        let strict = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_context(&mut ctx, &["15/03/2025", "20/04/2025", "bad"], &strict);
+        assert!(matches!(result, Err(DateInferError::StrictValidationFailed { failed_count: 1, .. })));
     }
 
     // =========================================
-    // InconsistentFormats tolerance tests
+    // Pinned position tests
     // =========================================
 
     #[test]
-    fn test_trailing_space_tolerated() {
-        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025 "];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d/%m/%Y");
+    fn test_pin_year_position() {
+        let dates = vec!["2025-01-02", "2025-03-04"];
+        let options = InferOptions {
+            pinned: vec![(0, TokenType::Year4)],
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d");
+        assert_eq!(result.token_types[0], TokenType::Year4);
     }
 
     #[test]
-    fn test_one_malformed_row_tolerated() {
-        // 4 good dates + 1 "N/A" → succeeds with reduced confidence
-        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025", "01/01/2025", "N/A"];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d/%m/%Y");
-        assert!(result.confidence < 1.0);
+    fn test_pin_overrides_day_first_preference() {
+        let dates = vec!["01/02/2025", "03/04/2025"];
+        let options = InferOptions {
+            pinned: vec![(0, TokenType::Month)],
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%m/%d/%Y");
     }
 
     #[test]
-    fn test_empty_string_tolerated() {
-        // good dates + one "" → succeeds
-        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025", ""];
-        let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d/%m/%Y");
+    fn test_pin_invalid() {
+        let dates = vec!["2025-01-02", "2025-03-04"];
+        for pin in [(9, TokenType::Year4), (0, TokenType::Hour24)] {
+            let options = InferOptions {
+                pinned: vec![pin],
+                ..Default::default()
+            };
+            let result = infer_with_options(&dates, &options);
+            assert!(matches!(result, Err(DateInferError::InvalidPin { index, .. }) if index == pin.0));
+        }
     }
 
     #[test]
-    fn test_inconsistent_formats_when_no_majority() {
-        // Truly mixed token counts with no >50% majority → still errors
-        let dates = vec!["15/03/2025", "2025-01-15T10:30:00", "Jan 2025"];
-        let result = infer(&dates);
-        assert!(matches!(result, Err(DateInferError::InconsistentFormats)));
+    fn test_assume_iso() {
+        let options = InferOptions {
+            assume_iso: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&["2025-01-02", "2025-03-04"], &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d");
+        let result = infer_with_options(&["20250102T103000", "20250304T144500"], &options).unwrap();
+        assert_eq!(result.format, "%Y%m%dT%H%M%S");
+
+        // Year-last columns are inferred as usual
+        let result = infer_with_options(&["01/02/2025", "03/04/2025"], &options).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
     }
 
     #[test]
-    fn test_confidence_reflects_filtered_proportion() {
-        // 4 good dates + 1 bad → confidence reduced proportionally
-        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025", "01/06/2025", "N/A"];
-        let result = infer(&dates).unwrap();
-        // filter_ratio = 4/5 = 0.8, so confidence should be at most 0.8
-        assert!(result.confidence <= 0.8 + f64::EPSILON);
-        assert!(result.confidence > 0.0);
+    fn test_assume_iso_rejects_day_first_row() {
+        let dates = vec!["2025-01-02", "2025-03-04", "2025-25-12"];
+        let options = InferOptions {
+            assume_iso: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options);
+        assert!(matches!(result, Err(DateInferError::NotIsoOrder { example }) if example == "2025-25-12"));
+        // Without the option the day-first row decides the format
+        assert_eq!(infer(&dates).unwrap().format, "%Y-%d-%m");
     }
 
     // =========================================
@@ -510,80 +3340,364 @@ mod tests {
     }
 
     #[test]
-    fn test_strict_false_ignores_issues() {
-        // strict=false tolerates bad dates in the dataset (as long as majority is consistent)
-        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025"];
+    fn test_strict_false_ignores_issues() {
+        // strict=false tolerates bad dates in the dataset (as long as majority is consistent)
+        let dates = vec!["15/03/2025", "20/04/2025", "25/12/2025"];
+        let options = InferOptions {
+            strict: false,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_strict_validates_all_dates_not_just_sample() {
+        // Dataset > 1000, bad date outside sample range, strict still catches it
+        let mut dates: Vec<String> = (0..1100)
+            .map(|i| format!("{:02}/03/2025", (i % 28) + 1))
+            .collect();
+        // Add an incompatible date at the end
+        dates.push("NOT-A-DATE".to_string());
+        let options = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_strict_fail_fast_stops_at_first_failure() {
+        let mut dates: Vec<String> = (0..1100)
+            .map(|i| format!("{:02}/03/2025", (i % 28) + 1))
+            .collect();
+        dates[500] = "NOT-A-DATE".to_string();
+        dates.push("ALSO-BAD".to_string());
+        let options = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options);
+        assert!(matches!(result, Err(DateInferError::StrictValidationFailed { failed_count: 2, .. })));
+
+        let options = InferOptions {
+            strict_fail_fast: true,
+            ..options
+        };
+        let result = infer_with_options(&dates, &options);
+        assert!(matches!(
+            result,
+            Err(DateInferError::StrictValidationFailed { failed_count: 1, total_count: 1101 })
+        ));
+    }
+
+    #[test]
+    fn test_strict_tolerance() {
+        let mut dates: Vec<String> = (0..100).map(|i| format!("{:02}/03/2025", (i % 28) + 1)).collect();
+        dates[10] = "NOT-A-DATE".to_string();
+        let options = |strict_tolerance| InferOptions {
+            strict: true,
+            strict_tolerance,
+            ..Default::default()
+        };
+
+        // 1 of 100 rows is exactly at a 1% tolerance
+        assert!(infer_with_options(&dates, &options(0.01)).is_ok());
+        assert!(infer_with_options(&dates, &options(0.0)).is_err());
+
+        dates[20] = "ALSO-BAD".to_string();
+        let result = infer_with_options(&dates, &options(0.01));
+        assert!(matches!(
+            result,
+            Err(DateInferError::StrictValidationFailed { failed_count: 2, total_count: 100 })
+        ));
+        assert!(infer_with_options(&dates, &options(0.02)).is_ok());
+    }
+
+    #[test]
+    fn test_on_phase_hook() {
+        use std::sync::Mutex;
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let options = InferOptions {
+            strict: true,
+            on_phase: Some(PhaseHook::new(move |phase, _| sink.lock().unwrap().push(phase))),
+            ..Default::default()
+        };
+        let result = infer_with_options(&["15/03/2025", "20/04/2025"], &options).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [Phase::Tokenize, Phase::Consensus, Phase::Rules, Phase::Format, Phase::Strict]
+        );
+    }
+
+    #[test]
+    fn test_strict_values_rejects_out_of_range_day() {
+        let options = InferOptions {
+            strict: true,
+            strict_values: true,
+            ..Default::default()
+        };
+        // Only the value check catches a day past the end of April
+        let dates = ["15/04/2025", "31/04/2025", "20/04/2025"];
+        let structural = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(infer_with_options(&dates, &structural).is_ok());
+        let result = infer_with_options(&dates, &options);
+        assert!(matches!(
+            result,
+            Err(DateInferError::StrictValidationFailed { failed_count: 1, .. })
+        ));
+        // Nor does 2025 have a 29 February
+        assert!(infer_with_options(&["15/02/2025", "29/02/2025", "20/02/2025"], &options).is_err());
+        assert!(infer_with_options(&["15/02/2024", "29/02/2024", "20/02/2024"], &options).is_ok());
+    }
+
+    #[test]
+    fn test_strict_values_checks_month_length() {
+        let dates = vec!["15/01/2025", "31/02/2025", "20/01/2025"];
+        let structural = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(infer_with_options(&dates, &structural).is_ok());
+
+        let options = InferOptions {
+            strict_values: true,
+            ..structural
+        };
+        assert!(infer_with_options(&dates, &options).is_err());
+
+        // Leap days and month names are validated against the row's year
+        assert!(infer_with_options(&["29/02/2024", "15/01/2024"], &options).is_ok());
+        assert!(infer_with_options(&["29 Feb 2025", "15 Jan 2025"], &options).is_err());
+    }
+
+    // =========================================
+    // Weekday and timezone tests
+    // =========================================
+
+    #[test]
+    fn test_weekday_month_day_time_tz_year() {
+        // hidateinfer's flagship example
+        let dates = vec![
+            "Mon Jan 13 09:52:52 MST 2014",
+            "Tue Jan 21 15:30:00 EST 2014",
+        ];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%a %b %d %H:%M:%S %Z %Y");
+    }
+
+    #[test]
+    fn test_weekday_only_variation() {
+        // Weekday varies, rest is consistent
+        let dates = vec![
+            "Mon 13 Jan 2014",
+            "Tue 21 Jan 2014",
+            "Wed 15 Feb 2014",
+        ];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%a %d %b %Y");
+    }
+
+    #[test]
+    fn test_verbose_weekday_with_commas() {
+        let dates = vec![
+            "Monday, January 13, 2014",
+            "Tuesday, February 4, 2014",
+            "Friday, March 07, 2014",
+        ];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%A, %B %d, %Y");
+    }
+
+    #[test]
+    fn test_verbose_weekday_all_ambiguous_days() {
+        // Every day value is <= 12; the month name still forces Day
+        let dates = vec!["Monday, January 6, 2014", "Saturday, February 1, 2014"];
+        let options = InferOptions {
+            prefer_dayfirst: false,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%A, %B %d, %Y");
+        assert_eq!(result.token_types[5], TokenType::Day);
+    }
+
+    #[test]
+    fn test_unusual_text_token_orderings() {
+        // Names resolve by type wherever they sit
+        let cases = [
+            (["13 Mon Jan 2014", "20 Thu Feb 2014"], "%d %a %b %Y"),
+            (["Jan Mon 13 2014", "Feb Thu 20 2014"], "%b %a %d %Y"),
+            (["2014 Jan 13 Mon", "2014 Feb 20 Thu"], "%Y %b %d %a"),
+            (["Mon 2014 Jan 13", "Thu 2014 Feb 20"], "%a %Y %b %d"),
+            // Ambiguous day values are still days next to a month name
+            (["03 Mon Mar 2025", "04 Tue Mar 2025"], "%d %a %b %Y"),
+            (["March Monday 03 2025", "March Tuesday 04 2025"], "%B %A %d %Y"),
+        ];
+        for (dates, format) in cases {
+            let result = infer(&dates).unwrap();
+            assert_eq!(result.format, format, "{dates:?}");
+            assert!(!result.used_preference);
+        }
+    }
+
+    #[test]
+    fn test_timezone_variation() {
+        // Different timezone abbreviations
+        let dates = vec![
+            "13 Jan 2014 09:52:52 MST",
+            "21 Jan 2014 15:30:00 EST",
+        ];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%d %b %Y %H:%M:%S %Z");
+    }
+
+    #[test]
+    fn test_tz_name_and_offset() {
+        let dates = vec![
+            "2014-01-13 09:52:52 EST -0500",
+            "2014-01-14 10:52:52 PST -0800",
+        ];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M:%S %Z %z");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_tz_offset_then_name() {
+        let dates = vec!["2014-01-13 09:52:52 -05:00 EST", "2014-01-14 10:52:52 +01:00 CET"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M:%S %z %Z");
+    }
+
+    #[test]
+    fn test_unlisted_tz_abbreviations() {
+        let dates = vec![
+            "Mon Jan 13 09:52:52 PDT 2014",
+            "Tue Jan 21 15:30:00 PDT 2014",
+        ];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%a %b %d %H:%M:%S %Z %Y");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        let dates = vec![
+            "2025-01-15 10:30:00 AEST",
+            "2025-01-16 11:30:00 AEDT",
+            "2025-01-17 12:30:00 JST",
+        ];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M:%S %Z");
+    }
+
+    #[test]
+    fn test_tz_abbreviation_spelled_like_month() {
+        // MAR / MAY after the time of a complete numeric date is a timezone
+        let dates = vec!["2025-01-15 10:30:00 MAR", "2025-03-20 14:45:00 MAY"];
         let options = InferOptions {
-            strict: false,
+            strict: true,
             ..Default::default()
         };
-        let result = infer_with_options(&dates, &options);
-        assert!(result.is_ok());
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M:%S %Z");
+        assert_eq!(result.token_types.last(), Some(&TokenType::TzName));
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        // It stays the month when the date needs it or text follows
+        let result = infer(&["15 2025 10:30 MAR", "16 2025 11:30 APR"]).unwrap();
+        assert_eq!(result.token_types.last(), Some(&TokenType::MonthNameShort));
+        let result = infer(&["15 10:30:00 MAR 2025", "20 14:45:00 MAR 2025"]).unwrap();
+        assert_eq!(result.format, "%d %H:%M:%S %b %Y");
     }
 
     #[test]
-    fn test_strict_validates_all_dates_not_just_sample() {
-        // Dataset > 1000, bad date outside sample range, strict still catches it
-        let mut dates: Vec<String> = (0..1100)
-            .map(|i| format!("{:02}/03/2025", (i % 28) + 1))
-            .collect();
-        // Add an incompatible date at the end
-        dates.push("NOT-A-DATE".to_string());
+    fn test_mixed_listed_and_unlisted_tz() {
+        let dates = vec!["2025-01-15 10:30:00 PST", "2025-06-16 11:30:00 PDT"];
         let options = InferOptions {
             strict: true,
             ..Default::default()
         };
-        let result = infer_with_options(&dates, &options);
-        assert!(result.is_err());
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M:%S %Z");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_uppercase_word_not_after_time_is_not_tz() {
+        // "ABC" precedes the date, not a time, so it stays a literal
+        let dates = vec!["ABC 15/03/2025", "ABC 20/04/2025"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "ABC %d/%m/%Y");
+
+        // Lowercase trailing words are labels, not timezones
+        let dates = vec!["2025-01-15 10:30:00 local", "2025-01-16 11:30:00 local"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M:%S local");
     }
 
     // =========================================
-    // Weekday and timezone tests
+    // AM/PM, subsecond, and timezone offset tests
     // =========================================
 
     #[test]
-    fn test_weekday_month_day_time_tz_year() {
-        // hidateinfer's flagship example
-        let dates = vec![
-            "Mon Jan 13 09:52:52 MST 2014",
-            "Tue Jan 21 15:30:00 EST 2014",
-        ];
+    fn test_ampm_12hour() {
+        let dates = vec!["01/15/2025 02:30:00 PM", "03/20/2025 10:45:00 AM"];
         let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%a %b %d %H:%M:%S %Z %Y");
+        assert_eq!(result.format, "%m/%d/%Y %I:%M:%S %p");
     }
 
     #[test]
-    fn test_weekday_only_variation() {
-        // Weekday varies, rest is consistent
-        let dates = vec![
-            "Mon 13 Jan 2014",
-            "Tue 21 Jan 2014",
-            "Wed 15 Feb 2014",
-        ];
+    fn test_ampm_midnight_and_noon() {
+        // 12 is valid for both %H and %I; AM/PM must keep it 12-hour
+        let dates = vec!["12:30 AM", "12:00 PM", "11:59 PM"];
         let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%a %d %b %Y");
+        assert_eq!(result.format, "%I:%M %p");
+        assert_eq!(result.token_types[0], TokenType::Hour12);
+
+        let result = infer(&["12:30 AM"]).unwrap();
+        assert_eq!(result.format, "%I:%M %p");
     }
 
     #[test]
-    fn test_timezone_variation() {
-        // Different timezone abbreviations
-        let dates = vec![
-            "13 Jan 2014 09:52:52 MST",
-            "21 Jan 2014 15:30:00 EST",
-        ];
+    fn test_ampm_midnight_with_date() {
+        let dates = vec!["01/15/2025 12:00:00 AM", "03/20/2025 12:00:00 PM"];
         let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%d %b %Y %H:%M:%S %Z");
+        assert_eq!(result.format, "%m/%d/%Y %I:%M:%S %p");
     }
 
-    // =========================================
-    // AM/PM, subsecond, and timezone offset tests
-    // =========================================
+    #[test]
+    fn test_short_times_with_ampm() {
+        let result = infer(&["3 PM", "11 AM", "12 PM"]).unwrap();
+        assert_eq!(result.format, "%I %p");
+        assert_eq!(result.token_types[0], TokenType::Hour12);
+
+        let result = infer(&["3PM", "11AM"]).unwrap();
+        assert_eq!(result.format, "%I%p");
+
+        let result = infer(&["3:30 PM", "11:45 AM", "12:00 PM"]).unwrap();
+        assert_eq!(result.format, "%I:%M %p");
+
+        let result = infer(&["15/03/2025 3 PM", "20/04/2025 11 AM"]).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y %I %p");
+    }
 
     #[test]
-    fn test_ampm_12hour() {
-        let dates = vec!["01/15/2025 02:30:00 PM", "03/20/2025 10:45:00 AM"];
+    fn test_ampm_before_time() {
+        let dates = vec!["PM 03:30", "AM 10:15", "PM 12:00"];
         let result = infer(&dates).unwrap();
-        assert_eq!(result.format, "%m/%d/%Y %I:%M:%S %p");
+        assert_eq!(result.format, "%p %I:%M");
+    }
+
+    #[test]
+    fn test_ampm_between_date_and_time() {
+        let dates = vec!["2025-01-15 PM 03:30:00", "2025-01-16 AM 10:15:00"];
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %p %I:%M:%S");
     }
 
     #[test]
@@ -614,6 +3728,17 @@ mod tests {
         assert_eq!(result.format, "%Y-%m-%dT%H:%M:%S%z");
     }
 
+    #[test]
+    fn test_tz_offset_with_seconds() {
+        // Python's %z accepts +HH:MM:SS, so the whole offset maps to %z
+        let dates = vec!["2025-01-15T10:30:00+05:30:00", "2025-03-20T14:45:30-03:00:00"];
+        let tokens = tokenize(dates[0]).unwrap();
+        assert_eq!(tokens.last().unwrap().value, "+05:30:00");
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%Y-%m-%dT%H:%M:%S%z");
+        assert_eq!(result.token_types.len(), 12);
+    }
+
     #[test]
     fn test_negative_tz_offset_with_colon() {
         let dates = vec!["2025-01-15T10:30:00-05:00", "2025-03-20T14:45:30-05:00"];
@@ -664,6 +3789,16 @@ mod tests {
         assert_eq!(result.format, "%m/%d/%Y");
     }
 
+    #[test]
+    fn test_prescan_ignores_month_name_header() {
+        // A stray first row naming a month doesn't switch the scan off
+        let mut dates = vec!["January".to_string()];
+        dates.extend((0..3000).map(|i| format!("{:02}/{:02}/2025", (i % 12) + 1, (i % 12) + 1)));
+        dates[1501] = "01/25/2025".to_string();
+        let result = infer(&dates).unwrap();
+        assert_eq!(result.format, "%m/%d/%Y");
+    }
+
     #[test]
     fn test_prescan_no_disambiguation_uses_preference() {
         // All ambiguous — prescan finds nothing, falls back to prefer_dayfirst
@@ -674,4 +3809,444 @@ mod tests {
         // Default prefer_dayfirst=true → DD/MM
         assert_eq!(result.format, "%d/%m/%Y");
     }
+
+    #[test]
+    fn test_sampling_is_deterministic() {
+        let mut dates: Vec<String> = (0..5_000).map(|i| format!("{:02}/{:02}/2025", (i % 12) + 1, (i % 12) + 1)).collect();
+        dates[7] = "25/06/2025".to_string();
+
+        let first = sample_dates(&dates, None);
+        assert_eq!(first.len(), 1000);
+        assert_eq!(*first[0], dates[0]);
+        assert_eq!(sample_dates(&dates, None), first);
+
+        // A seed shifts the start within one step and is stable across runs
+        let seeded = sample_dates(&dates, Some(3));
+        assert_eq!(*seeded[0], dates[3]);
+        assert_eq!(*seeded[1], dates[8]);
+        assert_eq!(sample_dates(&dates, Some(3)), seeded);
+        assert_eq!(sample_dates(&dates, Some(8)), seeded);
+        // The disambiguating dates are injected whatever the offset
+        assert!(seeded.iter().any(|d| **d == dates[7]));
+
+        let options = InferOptions {
+            sample_seed: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(infer_with_options(&dates, &options).unwrap().format, "%d/%m/%Y");
+    }
+
+    // =========================================
+    // Pre-tokenized input tests
+    // =========================================
+
+    fn manual_token(value: &str, types: &[TokenType]) -> Token {
+        Token {
+            value: value.to_string(),
+            position: 0,
+            possible_types: types.iter().copied().collect(),
+            numeric_value: value.parse().ok(),
+        }
+    }
+
+    #[test]
+    fn test_infer_from_tokens_manual() {
+        let sep = || manual_token("|", &[TokenType::Separator('|')]);
+        let tokenized = vec![
+            vec![
+                manual_token("2025", &[TokenType::Year4]),
+                sep(),
+                manual_token("03", &[TokenType::DayOrMonth]),
+                sep(),
+                manual_token("15", &[TokenType::Day]),
+            ],
+            vec![
+                manual_token("2025", &[TokenType::Year4]),
+                sep(),
+                manual_token("01", &[TokenType::DayOrMonth]),
+                sep(),
+                manual_token("02", &[TokenType::DayOrMonth]),
+            ],
+        ];
+        let result = infer_from_tokens(&tokenized, &InferOptions::default()).unwrap();
+        assert_eq!(result.format, "%Y|%m|%d");
+        assert_eq!(result.token_types[2], TokenType::Month);
+    }
+
+    #[test]
+    fn test_infer_from_tokens_errors() {
+        let empty: Vec<Vec<Token>> = vec![];
+        assert!(matches!(
+            infer_from_tokens(&empty, &InferOptions::default()),
+            Err(DateInferError::EmptyInput)
+        ));
+
+        let tokenized = vec![tokenize("15/03/2025").unwrap(), tokenize("2025-03").unwrap()];
+        assert!(matches!(
+            infer_from_tokens(&tokenized, &InferOptions::default()),
+            Err(DateInferError::InconsistentFormats { .. })
+        ));
+    }
+
+    // =========================================
+    // Analysis tests
+    // =========================================
+
+    #[test]
+    fn test_analyze_ambiguous_dataset() {
+        let analysis = analyze(&["01/02/2025", "03/04/2025", "15/04/2025"]);
+        assert_eq!(analysis.len(), 5);
+        assert!(analysis[1].is_separator);
+        assert_eq!(analysis[1].candidates, vec![(TokenType::Separator('/'), 3)]);
+
+        // Only the first two rows could be a month in the first slot
+        let first = &analysis[0];
+        assert!(!first.is_separator);
+        assert!(first.candidates.contains(&(TokenType::Day, 3)));
+        assert!(first.candidates.contains(&(TokenType::DayOrMonth, 2)));
+        assert!(first.candidates.windows(2).all(|w| w[0].1 >= w[1].1));
+
+        assert_eq!(analysis[4].candidates, vec![(TokenType::Year4, 3)]);
+    }
+
+    #[test]
+    fn test_infer_and_partition() {
+        let dates = vec![
+            "15/03/2025",
+            "garbage",
+            "20/04/2025",
+            "2025-01-15",
+            "01/02/2025",
+            "31/02/2025",
+            "",
+        ];
+        let (result, valid, invalid) = infer_and_partition(&dates).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        assert_eq!(valid, [0, 2, 4]);
+        // Wrong structure, wrong order and a day past the end of February
+        assert_eq!(invalid, [1, 3, 5, 6]);
+
+        // Strict is ignored: the mismatches are reported, not raised
+        let options = InferOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(infer_and_partition_with_options(&dates, &options).is_ok());
+
+        // Rows fit the optional shapes and the majority spellings, as in
+        // strict validation
+        let dates = vec!["2025-01-15 10:30", "2025-01-16 10:31:05", "2025-01-17 11:00", "2025-01-18 12:00"];
+        let options = InferOptions {
+            optional_seconds: true,
+            ..Default::default()
+        };
+        let (_, valid, invalid) = infer_and_partition_with_options(&dates, &options).unwrap();
+        assert_eq!(valid, [0, 1, 2, 3]);
+        assert!(invalid.is_empty());
+        let (_, valid, invalid) = infer_and_partition(&["15 Jan 2025", "20 Mar. 2025", "21 Apr 2025"]).unwrap();
+        assert_eq!(valid, [0, 2]);
+        assert_eq!(invalid, [1]);
+    }
+
+    #[test]
+    fn test_infer_with_votes() {
+        let dates = vec!["01/02/2025", "03/04/2025", "15/04/2025", "garbage"];
+        let (result, votes) = infer_with_votes(&dates, &InferOptions::default()).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+        // Only the three rows with the majority structure vote
+        assert_eq!(votes.len(), 5);
+        assert!(votes[0].candidates.contains(&(TokenType::Day, 3)));
+        assert!(votes[0].candidates.contains(&(TokenType::DayOrMonth, 2)));
+        assert!(votes[1].is_separator);
+        assert_eq!(votes[4].candidates, vec![(TokenType::Year4, 3)]);
+        assert_eq!(votes, analyze(&dates));
+    }
+
+    #[test]
+    fn test_looks_like_dates() {
+        let dates = ["15/03/2025", "Mar 15, 2025", "2025-01-15T10:30:00Z", "N/A"];
+        assert!((looks_like_dates(&dates) - 0.75).abs() < 1e-9);
+
+        let text = [
+            "The quick brown fox",
+            "I have 3 cats and 2 dogs",
+            "order #1042 shipped",
+            "see you at 5",
+        ];
+        assert_eq!(looks_like_dates(&text), 0.0);
+        assert_eq!(looks_like_dates::<&str>(&[]), 0.0);
+
+        // Lone numbers are integers or IDs, not dates
+        assert_eq!(looks_like_dates(&["3", "7", "12", "5"]), 0.0);
+        assert_eq!(looks_like_dates(&["1942", "2011", "1999"]), 0.0);
+        assert!((looks_like_dates(&["Jan 2025", "20250115"]) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_analyze_uses_majority_structure() {
+        let analysis = analyze(&["15/03/2025", "20/04/2025", "not a date at all"]);
+        assert_eq!(analysis.len(), 5);
+        assert!(analyze::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_explain_ambiguous_position() {
+        let dates = vec!["01/02/2025", "03/04/2025", "15/04/2025"];
+        let options = InferOptions::default();
+
+        // The month slot: every row votes Day/DayOrMonth, consensus picks Month
+        let explanation = explain_position(&dates, &options, 2).unwrap().unwrap();
+        assert_eq!(explanation.resolved, TokenType::Month);
+        assert!(explanation.candidates.contains(&(TokenType::DayOrMonth, 3)));
+        assert_eq!(explanation.confidence, Some(1.0));
+
+        let explanation = explain_position(&dates, &options, 1).unwrap().unwrap();
+        assert_eq!(explanation.resolved, TokenType::Separator('/'));
+        assert_eq!(explanation.confidence, None);
+
+        assert!(explain_position(&dates, &options, 5).unwrap().is_none());
+        assert!(matches!(
+            explain_position::<&str>(&[], &options, 0),
+            Err(DateInferError::EmptyInput)
+        ));
+    }
+
+    // =========================================
+    // Batch column tests
+    // =========================================
+
+    #[test]
+    fn test_infer_columns_progress_callbacks() {
+        let columns = vec![
+            vec!["15/03/2025", "20/04/2025"],
+            vec!["2025-01-15", "2025-03-20"],
+            vec![],
+        ];
+        let mut calls = Vec::new();
+        let results = infer_columns_with_progress(&columns, &InferOptions::default(), &mut |done, total| {
+            calls.push((done, total));
+        });
+        assert_eq!(calls, vec![(1, 3), (2, 3), (3, 3)]);
+        assert_eq!(results[0].as_ref().unwrap().format, "%d/%m/%Y");
+        assert_eq!(results[1].as_ref().unwrap().format, "%Y-%m-%d");
+        assert!(matches!(results[2], Err(DateInferError::EmptyInput)));
+    }
+
+    #[test]
+    fn test_infer_columns_no_columns() {
+        let columns: Vec<Vec<&str>> = vec![];
+        let mut calls = 0;
+        let results = infer_columns_with_progress(&columns, &InferOptions::default(), &mut |_, _| {
+            calls += 1;
+        });
+        assert!(results.is_empty());
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn test_infer_record_fields() {
+        let rows: Vec<Vec<String>> = [
+            vec!["1", "alice", "2025-01-15", "15/03/2025 10:30"],
+            vec!["2", "bob", "2025-03-20", "20/04/2025 11:45"],
+            // A short row leaves its missing fields out of their columns
+            vec!["3", "carol", "2025-06-01"],
+        ]
+        .iter()
+        .map(|row| row.iter().map(|v| v.to_string()).collect())
+        .collect();
+        let results = infer_record_fields(&rows, &InferOptions::default());
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_none());
+        assert!(results[1].is_none());
+        assert_eq!(results[2].as_ref().unwrap().format, "%Y-%m-%d");
+        assert_eq!(results[3].as_ref().unwrap().format, "%d/%m/%Y %H:%M");
+
+        let empty: Vec<Vec<&str>> = vec![];
+        assert!(infer_record_fields(&empty, &InferOptions::default()).is_empty());
+    }
+
+    // =========================================
+    // Optional seconds
+    // =========================================
+
+    #[test]
+    fn test_optional_seconds_mixed_rows() {
+        let dates = vec!["10:30", "10:30:00", "11:45:10", "12:00"];
+        let options = InferOptions {
+            optional_seconds: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%H:%M:%S");
+        assert_eq!(result.alternate_format.as_deref(), Some("%H:%M"));
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        // Without the option the 50/50 split has no majority
+        assert!(matches!(infer(&dates), Err(DateInferError::InconsistentFormats { .. })));
+    }
+
+    #[test]
+    fn test_optional_seconds_minority_with_seconds() {
+        let dates = vec!["2025-01-15 10:30", "2025-01-16 11:45", "2025-01-17 12:00:30"];
+        let options = InferOptions {
+            optional_seconds: true,
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M");
+        assert_eq!(result.alternate_format.as_deref(), Some("%Y-%m-%d %H:%M:%S"));
+    }
+
+    #[test]
+    fn test_optional_seconds_uniform_rows_unchanged() {
+        let dates = vec!["10:30:00", "11:45:10"];
+        let options = InferOptions {
+            optional_seconds: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%H:%M:%S");
+        assert!(result.alternate_format.is_none());
+    }
+
+    // =========================================
+    // Optional timezone
+    // =========================================
+
+    #[test]
+    fn test_optional_timezone_mixed_rows() {
+        let dates = vec![
+            "2025-01-15T10:30:00Z",
+            "2025-01-16T11:45:00Z",
+            "2025-01-17T12:00:30",
+            "2025-01-18T13:15:00Z",
+        ];
+        let options = InferOptions {
+            optional_timezone: true,
+            strict: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%dT%H:%M:%SZ");
+        assert_eq!(result.alternate_format.as_deref(), Some("%Y-%m-%dT%H:%M:%S"));
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        let dates = vec!["2025-01-15 10:30:00 UTC", "2025-01-16 11:45:00", "2025-01-17 12:00:30"];
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M:%S");
+        assert_eq!(result.alternate_format.as_deref(), Some("%Y-%m-%d %H:%M:%S %Z"));
+
+        // Without the option an even split has no majority length
+        let dates = vec!["2025-01-15T10:30:00+05:30", "2025-01-16T11:45:00"];
+        assert!(matches!(infer(&dates), Err(DateInferError::InconsistentFormats { .. })));
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%dT%H:%M:%S%z");
+
+        // `%z` reads both `Z` and offsets, whichever comes first
+        let dates = vec![
+            "2025-01-15T10:30:00Z",
+            "2025-01-16T11:45:00+05:30",
+            "2025-01-17T12:00:30",
+            "2025-01-18T13:15:00+01:00",
+        ];
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%dT%H:%M:%S%z");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+
+        // A minority abbreviation costs confidence and fails strict
+        let dates = vec![
+            "2025-01-15 10:30:00 UTC",
+            "2025-01-16 11:45:00 +0530",
+            "2025-01-17 12:00:30",
+            "2025-01-18 13:15:00 +0100",
+        ];
+        let loose = InferOptions {
+            optional_timezone: true,
+            ..Default::default()
+        };
+        let result = infer_with_options(&dates, &loose).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d %H:%M:%S %z");
+        assert!((result.confidence - 0.75).abs() < 1e-9);
+        assert!(matches!(
+            infer_with_options(&dates, &options),
+            Err(DateInferError::StrictValidationFailed { failed_count: 1, .. })
+        ));
+    }
+
+    // =========================================
+    // Round-trip verification
+    // =========================================
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_verify_optional_timezone() {
+        let options = InferOptions {
+            optional_timezone: true,
+            verify: 5,
+            ..Default::default()
+        };
+        let dates = vec![
+            "2025-01-15T10:30:00Z",
+            "2025-01-16T11:45:00+05:30",
+            "2025-01-17T12:00:30",
+            "2025-01-18T13:15:00+01:00",
+        ];
+        let result = infer_with_options(&dates, &options).unwrap();
+        assert_eq!(result.format, "%Y-%m-%dT%H:%M:%S%z");
+        assert!(parses_with("2025-01-15T10:30:00Z", &result.format, &[]));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_verify_parses_examples() {
+        let options = InferOptions {
+            verify: 5,
+            ..Default::default()
+        };
+        for dates in [
+            vec!["15/03/2025", "20/04/2025"],
+            vec!["2025-01-15T10:30:00.123Z", "2025-03-20T14:45:30.654Z"],
+            vec!["Mon Jan 13 09:52:52 MST 2014", "Tue Feb 11 10:02:02 EST 2014"],
+            vec!["2025-W03", "2025-W52"],
+        ] {
+            assert!(infer_with_options(&dates, &options).is_ok(), "{dates:?}");
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_verify_catches_unparseable_format() {
+        // A resolution that swapped day and month would put 15 in %m
+        let dates = ["15/03/2025", "20/04/2025"];
+        let types = infer(&dates).unwrap().token_types;
+        let options = InferOptions {
+            verify: 5,
+            ..Default::default()
+        };
+        let result = verify_examples(&dates, &types, "%m/%d/%Y", &options, &mut Vec::new());
+        assert!(matches!(
+            result,
+            Err(DateInferError::VerificationFailed { example, .. }) if example == "15/03/2025"
+        ));
+        assert!(verify_examples(&dates, &types, "%d/%m/%Y", &options, &mut Vec::new()).is_ok());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_verify_wildcard() {
+        let options = InferOptions {
+            unknown_policy: UnknownPolicy::Wildcard,
+            verify: 5,
+            ..Default::default()
+        };
+        let result = infer_with_options(&["15/03/2025 foo", "20/04/2025 bar"], &options).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y %?");
+
+        // Each `%?` reads its own text; the rest of the format still counts
+        assert!(parses_with("15/03/2025 50%", "%d/%m/%Y %?", &["50%"]));
+        assert!(!parses_with("15/03/2025 foo", "%d/%m/%Y %?", &["bar"]));
+        assert!(!parses_with("15/13/2025 foo", "%d/%m/%Y %?", &["foo"]));
+        assert!(parses_with("100%d 15", "100%%d %d", &[]));
+    }
 }
@@ -5,6 +5,8 @@
 //! DD/MM vs MM/DD ordering. This module scans ALL dates with minimal work —
 //! just byte-level digit extraction — to locate such dates.
 
+use crate::constraints::month_number;
+
 /// Scan all dates for disambiguating indices.
 ///
 /// A "disambiguating" date has a 1-2 digit numeric segment with value > 12
@@ -13,9 +15,22 @@
 ///
 /// Returns `[Option<usize>; 2]` — one representative date index per numeric
 /// position (0 and 1). Short-circuits once both positions are covered.
+///
+/// Dates that name their month (`15 Jan 2025`) have no day/month ambiguity
+/// to resolve, and with a single numeric date slot the scan would never
+/// short-circuit. If most of an evenly spaced probe of the dates name their
+/// month, the scan is skipped; a stray header row doesn't decide it.
 pub fn find_disambiguating_indices<S: AsRef<str>>(dates: &[S]) -> [Option<usize>; 2] {
+    const PROBE: usize = 64;
     let mut result: [Option<usize>; 2] = [None; 2];
 
+    let step = (dates.len() / PROBE).max(1);
+    let probed = dates.iter().step_by(step).count();
+    let named = dates.iter().step_by(step).filter(|d| names_month(d.as_ref())).count();
+    if named * 2 > probed {
+        return result;
+    }
+
     for (idx, date) in dates.iter().enumerate() {
         let bytes = date.as_ref().as_bytes();
         let mut num_pos: usize = 0; // which numeric segment we're on
@@ -66,6 +81,12 @@ pub fn find_disambiguating_indices<S: AsRef<str>>(dates: &[S]) -> [Option<usize>
     result
 }
 
+/// Check whether any run of ASCII letters in `date` is a month name
+fn names_month(date: &str) -> bool {
+    date.split(|c: char| !c.is_ascii_alphabetic())
+        .any(|word| word.len() >= 3 && month_number(word).is_some())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,4 +162,11 @@ mod tests {
         assert_eq!(result[0], None);
         assert_eq!(result[1], None);
     }
+
+    #[test]
+    fn test_skips_month_name_dates() {
+        // A single numeric date slot: nothing to disambiguate
+        let dates = vec!["01 Jan 2025", "15 Mar 2025", "20 April 2025"];
+        assert_eq!(find_disambiguating_indices(&dates), [None, None]);
+    }
 }
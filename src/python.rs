@@ -1,9 +1,78 @@
 //! Python bindings for fastdateinfer via PyO3
 
 use pyo3::prelude::*;
+use pyo3::create_exception;
 use pyo3::exceptions::PyValueError;
+use pyo3::types::PyDict;
 
-use crate::{infer_with_options, InferOptions, InferResult as RustInferResult};
+use crate::{infer_with_options, DateInferError, InferOptions, InferResult as RustInferResult, TokenType};
+
+create_exception!(fastdateinfer, InferenceError, PyValueError, "Base class for inference failures.");
+create_exception!(fastdateinfer, EmptyInputError, InferenceError, "No dates to infer from.");
+create_exception!(
+    fastdateinfer,
+    InconsistentFormatsError,
+    InferenceError,
+    "No token structure is shared by a majority of the dates."
+);
+create_exception!(
+    fastdateinfer,
+    LowConfidenceError,
+    InferenceError,
+    "Confidence fell below min_confidence."
+);
+create_exception!(
+    fastdateinfer,
+    StrictValidationError,
+    InferenceError,
+    "Some dates don't match the inferred format in strict mode."
+);
+create_exception!(
+    fastdateinfer,
+    AmbiguousDateError,
+    InferenceError,
+    "Day and month could not be told apart."
+);
+
+/// Python exception class raised for an error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExceptionClass {
+    Inference,
+    EmptyInput,
+    InconsistentFormats,
+    LowConfidence,
+    StrictValidation,
+    Ambiguous,
+}
+
+impl ExceptionClass {
+    fn of(err: &DateInferError) -> Self {
+        match err {
+            DateInferError::EmptyInput | DateInferError::NoDateTokens { .. } => Self::EmptyInput,
+            DateInferError::InconsistentFormats { .. } => Self::InconsistentFormats,
+            DateInferError::LowConfidence { .. } => Self::LowConfidence,
+            DateInferError::StrictValidationFailed { .. } => Self::StrictValidation,
+            DateInferError::UnresolvableAmbiguity => Self::Ambiguous,
+            _ => Self::Inference,
+        }
+    }
+
+    fn new_err(self, message: String) -> PyErr {
+        match self {
+            Self::Inference => InferenceError::new_err(message),
+            Self::EmptyInput => EmptyInputError::new_err(message),
+            Self::InconsistentFormats => InconsistentFormatsError::new_err(message),
+            Self::LowConfidence => LowConfidenceError::new_err(message),
+            Self::StrictValidation => StrictValidationError::new_err(message),
+            Self::Ambiguous => AmbiguousDateError::new_err(message),
+        }
+    }
+}
+
+/// Convert an inference error to its Python exception
+fn to_py_err(err: DateInferError) -> PyErr {
+    ExceptionClass::of(&err).new_err(err.to_string())
+}
 
 /// Result of date format inference (Python class)
 #[pyclass(name = "InferResult")]
@@ -15,9 +84,44 @@ pub struct PyInferResult {
     /// Confidence score (0.0 - 1.0)
     #[pyo3(get)]
     pub confidence: f64,
-    /// Resolved token types as strings
+    /// Resolved token types as stable tags (e.g. "day", "sep:/")
     #[pyo3(get)]
     pub token_types: Vec<String>,
+    /// Number of inputs skipped as nulls
+    #[pyo3(get)]
+    pub skipped_count: usize,
+    /// Whether prefer_dayfirst chose the day/month order
+    #[pyo3(get)]
+    pub used_preference: bool,
+    /// Per-position components, exposed as dicts by the `components` getter
+    components: Vec<Component>,
+}
+
+/// One resolved token position of a result
+#[derive(Debug, Clone, PartialEq)]
+struct Component {
+    /// Stable tag, as in `token_types`
+    tag: String,
+    /// strptime specifier of the type, or the literal separator character
+    specifier: String,
+    /// Index of the token in the examples
+    position: usize,
+}
+
+/// Pair each resolved type with its specifier and position
+fn components_of(token_types: &[TokenType]) -> Vec<Component> {
+    token_types
+        .iter()
+        .enumerate()
+        .map(|(position, token_type)| Component {
+            tag: token_type.tag(),
+            specifier: match token_type {
+                TokenType::Separator(c) => c.to_string(),
+                _ => token_type.strptime_format().to_string(),
+            },
+            position,
+        })
+        .collect()
 }
 
 #[pymethods]
@@ -32,18 +136,43 @@ impl PyInferResult {
     fn __str__(&self) -> String {
         self.format.clone()
     }
+
+    /// Per-position components as `{"type", "specifier", "position"}` dicts
+    #[getter]
+    fn components<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        self.components
+            .iter()
+            .map(|component| {
+                let dict = PyDict::new_bound(py);
+                dict.set_item("type", &component.tag)?;
+                dict.set_item("specifier", &component.specifier)?;
+                dict.set_item("position", component.position)?;
+                Ok(dict)
+            })
+            .collect()
+    }
+
+    /// An anchored regex matching the inferred structure, one capture
+    /// group per specifier (usable with Python's `re`).
+    fn to_regex(&self) -> String {
+        crate::format::to_regex(&self.format)
+    }
 }
 
 impl From<RustInferResult> for PyInferResult {
     fn from(result: RustInferResult) -> Self {
+        let components = components_of(&result.token_types);
         PyInferResult {
             format: result.format,
             confidence: result.confidence,
             token_types: result
                 .token_types
                 .into_iter()
-                .map(|t| format!("{:?}", t))
+                .map(|t| t.tag())
                 .collect(),
+            skipped_count: result.skipped_count,
+            used_preference: result.used_preference,
+            components,
         }
     }
 }
@@ -58,12 +187,15 @@ impl From<RustInferResult> for PyInferResult {
 ///     prefer_dayfirst: Prefer DD/MM format for ambiguous dates (default: True)
 ///     min_confidence: Minimum confidence threshold (default: 0.0)
 ///     strict: Fail if any example doesn't match (default: False)
+///     skip_nulls: Skip null markers like "", "N/A", "null" instead of
+///         counting them as unparseable (default: False)
 ///
 /// Returns:
 ///     InferResult with format string and confidence score
 ///
 /// Raises:
-///     ValueError: If inference fails
+///     InferenceError: If inference fails (a ValueError subclass; see the
+///         module docs for the specific subclasses)
 ///
 /// Example:
 ///     >>> import fastdateinfer
@@ -73,22 +205,25 @@ impl From<RustInferResult> for PyInferResult {
 ///     >>> print(result.confidence)
 ///     1.0
 #[pyfunction]
-#[pyo3(signature = (dates, prefer_dayfirst=true, min_confidence=0.0, strict=false))]
+#[pyo3(signature = (dates, prefer_dayfirst=true, min_confidence=0.0, strict=false, skip_nulls=false))]
 fn infer(
     dates: Vec<String>,
     prefer_dayfirst: bool,
     min_confidence: f64,
     strict: bool,
+    skip_nulls: bool,
 ) -> PyResult<PyInferResult> {
     let options = InferOptions {
         prefer_dayfirst,
         min_confidence,
         strict,
+        skip_nulls,
+        ..Default::default()
     };
 
     infer_with_options(&dates, &options)
         .map(PyInferResult::from)
-        .map_err(|e| PyValueError::new_err(e.to_string()))
+        .map_err(to_py_err)
 }
 
 /// Infer date format and return just the format string.
@@ -112,13 +247,12 @@ fn infer(
 fn infer_format(dates: Vec<String>, prefer_dayfirst: bool) -> PyResult<String> {
     let options = InferOptions {
         prefer_dayfirst,
-        min_confidence: 0.0,
-        strict: false,
+        ..Default::default()
     };
 
     infer_with_options(&dates, &options)
         .map(|r| r.format)
-        .map_err(|e| PyValueError::new_err(e.to_string()))
+        .map_err(to_py_err)
 }
 
 /// Infer date formats for multiple columns at once.
@@ -149,20 +283,17 @@ fn infer_batch(
 
     let options = InferOptions {
         prefer_dayfirst,
-        min_confidence: 0.0,
-        strict: false,
+        ..Default::default()
     };
 
     let columns_vec: Vec<(String, Vec<String>)> = columns.into_iter().collect();
 
-    let results: Vec<(String, std::result::Result<PyInferResult, String>)> =
+    let results: Vec<(String, std::result::Result<PyInferResult, DateInferError>)> =
         py.allow_threads(|| {
             columns_vec
                 .into_par_iter()
                 .map(|(name, dates)| {
-                    let result = infer_with_options(&dates, &options)
-                        .map(PyInferResult::from)
-                        .map_err(|e| format!("Column '{}': {}", name, e));
+                    let result = infer_with_options(&dates, &options).map(PyInferResult::from);
                     (name, result)
                 })
                 .collect()
@@ -172,7 +303,10 @@ fn infer_batch(
     for (name, result) in results {
         match result {
             Ok(r) => { map.insert(name, r); }
-            Err(e) => return Err(PyValueError::new_err(e)),
+            Err(e) => {
+                let class = ExceptionClass::of(&e);
+                return Err(class.new_err(format!("Column '{}': {}", name, e)));
+            }
         }
     }
 
@@ -185,6 +319,11 @@ fn infer_batch(
 /// example date strings. Unlike per-element parsers (pandas, dateutil),
 /// it analyzes ALL examples together to resolve ambiguous dates.
 ///
+/// Failures raise ``InferenceError`` (a ``ValueError``) or one of its
+/// subclasses: ``EmptyInputError``, ``InconsistentFormatsError``,
+/// ``LowConfidenceError``, ``StrictValidationError`` and
+/// ``AmbiguousDateError``.
+///
 /// Example:
 ///     >>> import fastdateinfer
 ///     >>> # If you have "15/03/2025", we know it's DD/MM/YYYY
@@ -199,8 +338,64 @@ fn fastdateinfer(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(infer_format, m)?)?;
     m.add_function(wrap_pyfunction!(infer_batch, m)?)?;
 
+    m.add("InferenceError", m.py().get_type_bound::<InferenceError>())?;
+    m.add("EmptyInputError", m.py().get_type_bound::<EmptyInputError>())?;
+    m.add("InconsistentFormatsError", m.py().get_type_bound::<InconsistentFormatsError>())?;
+    m.add("LowConfidenceError", m.py().get_type_bound::<LowConfidenceError>())?;
+    m.add("StrictValidationError", m.py().get_type_bound::<StrictValidationError>())?;
+    m.add("AmbiguousDateError", m.py().get_type_bound::<AmbiguousDateError>())?;
+
     // Add version info
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
 
     Ok(())
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_exception_class_mapping() {
+        let cases = [
+            (DateInferError::EmptyInput, ExceptionClass::EmptyInput),
+            (DateInferError::NoDateTokens { input_count: 2 }, ExceptionClass::EmptyInput),
+            (
+                DateInferError::InconsistentFormats { length_counts: BTreeMap::new() },
+                ExceptionClass::InconsistentFormats,
+            ),
+            (DateInferError::LowConfidence { got: 0.5, required: 0.9 }, ExceptionClass::LowConfidence),
+            (
+                DateInferError::StrictValidationFailed { failed_count: 1, total_count: 3 },
+                ExceptionClass::StrictValidation,
+            ),
+            (DateInferError::UnresolvableAmbiguity, ExceptionClass::Ambiguous),
+            (DateInferError::NoValidPattern, ExceptionClass::Inference),
+        ];
+        for (err, class) in cases {
+            assert_eq!(ExceptionClass::of(&err), class, "{err}");
+        }
+    }
+
+    #[test]
+    fn test_components_mapping() {
+        let result = crate::infer(&["15/03/2025 10:30", "20/04/2025 11:45"]).unwrap();
+        let components = components_of(&result.token_types);
+        assert_eq!(components.len(), result.token_types.len());
+        assert_eq!(
+            components[0],
+            Component {
+                tag: "day".to_string(),
+                specifier: "%d".to_string(),
+                position: 0,
+            }
+        );
+        assert_eq!(components[1].specifier, "/");
+        assert_eq!(components[1].tag, "sep:/");
+        let specifiers: String = components.iter().map(|c| c.specifier.as_str()).collect();
+        assert_eq!(specifiers, result.format);
+        assert_eq!(components[8].position, 8);
+    }
+}
@@ -0,0 +1,115 @@
+//! Streaming inference over line-delimited input
+//!
+//! Lines are read one at a time and thinned to an evenly spaced sample, so
+//! memory stays bounded no matter how large the input is. Strict mode is the
+//! exception: it must check every row, so all lines are kept.
+
+use std::io::BufRead;
+
+use crate::prescan::find_disambiguating_indices;
+use crate::{infer_with_options, InferOptions, InferResult, Result};
+
+/// Lines kept before the buffer is thinned to every other line
+const SAMPLE_CAP: usize = 2000;
+
+/// Infer date format from a reader with one date per line.
+///
+/// Blank lines are skipped. Without `strict`, at most a few thousand lines
+/// are buffered: an evenly spaced sample plus up to two lines that
+/// disambiguate day/month order, as with the in-memory pre-scan.
+///
+/// # Errors
+///
+/// * [`DateInferError::Io`](crate::DateInferError::Io) if reading fails
+/// * Any error from [`infer_with_options`]
+///
+/// # Example
+///
+/// ```
+/// use std::io::Cursor;
+/// use fastdateinfer::{infer_from_reader, InferOptions};
+///
+/// let input = Cursor::new("01/02/2025\n\n15/03/2025\n");
+/// let result = infer_from_reader(input, &InferOptions::default()).unwrap();
+/// assert_eq!(result.format, "%d/%m/%Y");
+/// ```
+pub fn infer_from_reader<R: BufRead>(reader: R, options: &InferOptions) -> Result<InferResult> {
+    let mut sample: Vec<String> = Vec::new();
+    let mut disambiguating: [Option<String>; 2] = [None, None];
+    let mut stride = 1;
+    let mut seen = 0;
+
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if !options.strict {
+            // Keep the first date proving each day/month slot, even if thinned out
+            let found = find_disambiguating_indices(&[line.as_str()]);
+            for (slot, hit) in disambiguating.iter_mut().zip(found) {
+                if slot.is_none() && hit.is_some() {
+                    *slot = Some(line.clone());
+                }
+            }
+        }
+
+        if seen % stride == 0 {
+            sample.push(line);
+            if !options.strict && sample.len() == SAMPLE_CAP {
+                // Drop every other line and halve the rate of future keeps
+                let mut index = 0;
+                sample.retain(|_| {
+                    index += 1;
+                    index % 2 == 1
+                });
+                stride *= 2;
+            }
+        }
+        seen += 1;
+    }
+
+    sample.extend(disambiguating.into_iter().flatten());
+    infer_with_options(&sample, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DateInferError;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_infer_from_cursor() {
+        let input = Cursor::new("2025-01-15\n2025-03-20\n\n   \n2025-12-01\n");
+        let result = infer_from_reader(input, &InferOptions::default()).unwrap();
+        assert_eq!(result.format, "%Y-%m-%d");
+        assert!((result.confidence - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_infer_from_reader_keeps_rare_disambiguating_line() {
+        // 100k ambiguous rows; the only day > 12 is thinned out of the sample
+        let mut input = String::new();
+        for i in 0..100_000 {
+            if i == 50_001 {
+                input.push_str("25/03/2025\n");
+            } else {
+                input.push_str("01/02/2025\n");
+            }
+        }
+        let options = InferOptions {
+            prefer_dayfirst: false,
+            ..Default::default()
+        };
+        let result = infer_from_reader(Cursor::new(input), &options).unwrap();
+        assert_eq!(result.format, "%d/%m/%Y");
+    }
+
+    #[test]
+    fn test_infer_from_empty_reader() {
+        let result = infer_from_reader(Cursor::new("\n\n"), &InferOptions::default());
+        assert!(matches!(result, Err(DateInferError::EmptyInput)));
+    }
+}
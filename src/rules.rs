@@ -238,4 +238,37 @@ mod tests {
         rule_month_name_adjacency(&mut tokens);
         assert_eq!(tokens[0], TokenType::Day);
     }
+
+    #[test]
+    fn test_month_name_adjacency_between_commas() {
+        // "Monday, January 6, 2014"
+        let mut tokens = vec![
+            TokenType::WeekdayName,
+            TokenType::Separator(','),
+            TokenType::Separator(' '),
+            TokenType::MonthName,
+            TokenType::Separator(' '),
+            TokenType::DayOrMonth,
+            TokenType::Separator(','),
+            TokenType::Separator(' '),
+            TokenType::Year4,
+        ];
+        rule_month_name_adjacency(&mut tokens);
+        assert_eq!(tokens[5], TokenType::Day);
+        assert_eq!(tokens[0], TokenType::WeekdayName);
+    }
+
+    #[test]
+    fn test_ampm_hour12_when_ampm_leads() {
+        let mut tokens = vec![
+            TokenType::AmPm,
+            TokenType::Separator(' '),
+            TokenType::Hour24,
+            TokenType::Separator(':'),
+            TokenType::Minute,
+        ];
+        rule_ampm_hour12(&mut tokens);
+        assert_eq!(tokens[0], TokenType::AmPm);
+        assert_eq!(tokens[2], TokenType::Hour12);
+    }
 }
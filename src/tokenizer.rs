@@ -1,13 +1,15 @@
 //! Tokenizer for date strings
 
 use crate::constraints::{
-    is_separator, possible_types_for_number, token_type_for_text, TokenType,
+    is_cjk_marker, is_separator, possible_types_for_number, token_type_for_text, TokenType, MONTH_NAMES_FULL,
+    TIME_WORDS,
 };
 use crate::error::{DateInferError, Result};
+use crate::InferOptions;
 use smallvec::SmallVec;
 
-/// Compact storage for possible token types (inline up to 6 types, no heap allocation)
-pub type TypeSet = SmallVec<[TokenType; 6]>;
+/// Compact storage for possible token types (inline up to 8 types, no heap allocation)
+pub type TypeSet = SmallVec<[TokenType; 8]>;
 
 /// A token extracted from a date string
 #[derive(Debug, Clone)]
@@ -16,7 +18,7 @@ pub struct Token {
     pub value: String,
     /// Position in the original string
     pub position: u16,
-    /// Possible token types based on constraints (inline storage, no heap for ≤6 types)
+    /// Possible token types based on constraints (inline storage, no heap for ≤8 types)
     pub possible_types: TypeSet,
     /// The parsed numeric value (if applicable)
     pub numeric_value: Option<u32>,
@@ -36,10 +38,10 @@ impl Token {
     }
 
     /// Create a new numeric token
-    fn numeric(value: &str, position: usize) -> Self {
+    fn numeric(value: &str, position: usize, options: &InferOptions) -> Self {
         let parsed: Option<u32> = value.parse().ok();
         let types = if let Some(num) = parsed {
-            possible_types_for_number(num, value.len())
+            possible_types_for_number(num, value.len(), &options.year_range)
         } else {
             let mut set = TypeSet::new();
             set.push(TokenType::Unknown);
@@ -53,13 +55,21 @@ impl Token {
         }
     }
 
-    /// Create a new text token
-    fn text(value: &str, position: usize) -> Self {
-        let token_type = token_type_for_text(value);
+    /// Create a new text token, taking ownership of the collected letters
+    fn text(value: String, position: usize, options: &InferOptions) -> Self {
+        let token_type = if options.allow_time_words && TIME_WORDS.iter().any(|w| w.eq_ignore_ascii_case(&value)) {
+            TokenType::TimeWord
+        } else {
+            token_type_for_text(&value, options.case_sensitive)
+        };
         let mut types = TypeSet::new();
         types.push(token_type);
+        // "May" is both the short and the full name
+        if token_type == TokenType::MonthNameShort && MONTH_NAMES_FULL.iter().any(|m| m.eq_ignore_ascii_case(&value)) {
+            types.push(TokenType::MonthName);
+        }
         Self {
-            value: value.to_string(),
+            value,
             position: position as u16,
             possible_types: types,
             numeric_value: None,
@@ -88,29 +98,104 @@ impl Token {
         self.possible_types.contains(&TokenType::Day)
             && !self.possible_types.iter().any(|t| matches!(t, TokenType::DayOrMonth | TokenType::Month))
     }
+
+    /// Check if this token can only be a 2-digit year among date types
+    /// (value > 31); 32-59 still fit a minute or second
+    pub fn must_be_year2(&self) -> bool {
+        self.value.len() == 2
+            && self.numeric_value.is_some_and(|v| v > 31)
+            && self.possible_types.contains(&TokenType::Year2)
+            && !self.could_be_day()
+            && !self.could_be_month()
+    }
 }
 
-/// Tokenize a date string into components
+/// Tokenize a date string into components using default options
+///
+/// # Example
+///
+/// ```
+/// use fastdateinfer::tokenize;
+///
+/// let tokens = tokenize("15/03/2025").unwrap();
+/// assert_eq!(tokens.len(), 5);
+/// assert_eq!(tokens[0].value, "15");
+/// ```
 pub fn tokenize(input: &str) -> Result<Vec<Token>> {
+    tokenize_with_options(input, &InferOptions::default())
+}
+
+/// Tokenize a date string into components
+///
+/// Brackets or quotes wrapping the whole value (`[2025-01-15 10:30:00]`,
+/// `"15/03/2025"`) are stripped first, so only the inner date is tokenized
+/// and the inferred format describes the unwrapped value.
+pub fn tokenize_with_options(input: &str, options: &InferOptions) -> Result<Vec<Token>> {
     let mut tokens = Vec::new();
-    let mut chars = input.chars().peekable();
-    let mut position = 0;
+    tokenize_into(input, options, &mut tokens)?;
+    Ok(tokens)
+}
+
+/// Tokenize into `tokens`, replacing its contents but keeping its capacity
+pub(crate) fn tokenize_into(input: &str, options: &InferOptions, tokens: &mut Vec<Token>) -> Result<()> {
+    tokens.clear();
+    let (inner, mut position) = strip_wrapping(input);
+    let mut chars = inner.chars().peekable();
 
     while let Some(&c) = chars.peek() {
-        if c.is_ascii_digit() {
+        if ascii_digit(c, options).is_some() {
             // Collect all consecutive digits
             let start = position;
             let mut num_str = String::new();
             while let Some(&c) = chars.peek() {
-                if c.is_ascii_digit() {
-                    num_str.push(c);
+                if let Some(digit) = ascii_digit(c, options) {
+                    num_str.push(digit);
                     chars.next();
                     position += 1;
                 } else {
                     break;
                 }
             }
-            tokens.push(Token::numeric(&num_str, start));
+            let after_t = tokens.last().is_some_and(|t| t.possible_types.first() == Some(&TokenType::Separator('T')));
+            let fiscal_prefix = options.fiscal_years
+                && tokens.last().is_some_and(|t| t.value == "'" || t.value.eq_ignore_ascii_case("FY"));
+            if fiscal_prefix && num_str.len() == 2 {
+                // '25 and FY25 are two-digit years
+                let mut types = TypeSet::new();
+                types.push(TokenType::Year2);
+                tokens.push(Token {
+                    value: num_str.clone(),
+                    position: start as u16,
+                    possible_types: types,
+                    numeric_value: num_str.parse().ok(),
+                });
+            } else if is_compact_date(&num_str, options) {
+                // Basic ISO date: YYYYMMDD
+                tokens.push(Token::numeric(&num_str[..4], start, options));
+                tokens.push(Token::numeric(&num_str[4..6], start + 4, options));
+                tokens.push(Token::numeric(&num_str[6..], start + 6, options));
+            } else if after_t && is_compact_time(&num_str) {
+                // Basic ISO time after 'T': HHMM or HHMMSS
+                let parts = [TokenType::Hour24, TokenType::Minute, TokenType::Second];
+                for (i, token_type) in parts.iter().take(num_str.len() / 2).enumerate() {
+                    let value = &num_str[i * 2..i * 2 + 2];
+                    let mut types = TypeSet::new();
+                    types.push(*token_type);
+                    tokens.push(Token {
+                        value: value.to_string(),
+                        position: (start + i * 2) as u16,
+                        possible_types: types,
+                        numeric_value: value.parse().ok(),
+                    });
+                }
+            } else {
+                tokens.push(Token::numeric(&num_str, start, options));
+            }
+        } else if is_cjk_marker(c) {
+            // 年/月/日 are alphabetic but act as separators
+            push_separator(tokens, c, position);
+            chars.next();
+            position += 1;
         } else if c.is_alphabetic() {
             // Collect all consecutive letters
             // This must come before is_separator() because 'T' is both alphabetic
@@ -118,7 +203,7 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>> {
             let start = position;
             let mut text = String::new();
             while let Some(&c) = chars.peek() {
-                if c.is_alphabetic() {
+                if c.is_alphabetic() && !is_cjk_marker(c) {
                     text.push(c);
                     chars.next();
                     position += 1;
@@ -128,9 +213,32 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>> {
             }
             // Standalone "T" after a numeric token is an ISO datetime separator
             if text == "T" && tokens.last().is_some_and(|t| t.numeric_value.is_some()) {
-                tokens.push(Token::separator('T', start));
+                push_separator(tokens, 'T', start);
+            } else if let Some(len) = attached_offset_len(&text, chars.clone()) {
+                // GMT+05:30 / UTC-0800: one offset token, prefix kept as a literal
+                text.extend(chars.by_ref().take(len));
+                position += len;
+                let mut types = TypeSet::new();
+                types.push(TokenType::TzOffset);
+                tokens.push(Token {
+                    value: text,
+                    position: start as u16,
+                    possible_types: types,
+                    numeric_value: None,
+                });
             } else {
-                tokens.push(Token::text(&text, start));
+                let mut token = Token::text(text, start, options);
+                // "Jan." ending a word: the period belongs to the abbreviation
+                if token.possible_types.contains(&TokenType::MonthNameShort) && chars.peek() == Some(&'.') {
+                    let mut ahead = chars.clone();
+                    ahead.next();
+                    if ahead.peek().map_or(true, |c| c.is_whitespace() || *c == ',') {
+                        token.value.push('.');
+                        chars.next();
+                        position += 1;
+                    }
+                }
+                tokens.push(token);
             }
         } else if c == '+' || c == '-' {
             // Could be timezone offset like +05:30 or -0800
@@ -142,7 +250,7 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>> {
             // Check if followed by digits AND in time context (has ':' in previous tokens)
             let followed_by_digit = chars.peek().is_some_and(|d| d.is_ascii_digit());
             let in_time_context = tokens.iter().rev().take(8).any(|t| {
-                t.possible_types.first() == Some(&TokenType::Separator(':'))
+                matches!(t.possible_types.first(), Some(TokenType::Separator(':' | 'T')))
             });
 
             if followed_by_digit && in_time_context {
@@ -166,13 +274,18 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>> {
                 });
             } else if is_separator(sign) {
                 // '-' is a valid date separator
-                tokens.push(Token::separator(sign, start));
+                push_separator(tokens, sign, start);
             } else {
                 // '+' not in time context, skip as unknown
-                tokens.push(Token::separator(sign, start));
+                push_separator(tokens, sign, start);
             }
         } else if is_separator(c) {
-            tokens.push(Token::separator(c, position));
+            push_separator(tokens, c, position);
+            chars.next();
+            position += 1;
+        } else if c == '\'' && options.fiscal_years && starts_abbreviated_year(chars.clone()) {
+            // Keep the apostrophe of '25 as a literal
+            push_separator(tokens, c, position);
             chars.next();
             position += 1;
         } else {
@@ -185,8 +298,132 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>> {
     if tokens.is_empty() {
         return Err(DateInferError::TokenizeError(input.to_string()));
     }
+    if options.allow_hour_24 {
+        mark_end_of_day(tokens);
+    }
 
-    Ok(tokens)
+    Ok(())
+}
+
+/// Let a `24` that starts an all-zero time (`24:00`, `24:00:00`) be an hour
+fn mark_end_of_day(tokens: &mut [Token]) {
+    for i in 0..tokens.len() {
+        if tokens[i].value != "24" {
+            continue;
+        }
+        let mut fields = tokens[i + 1..].chunks_exact(2).take_while(|pair| pair[0].value == ":").peekable();
+        if fields.peek().is_some() && fields.all(|pair| pair[1].numeric_value == Some(0)) {
+            tokens[i].possible_types.push(TokenType::Hour24);
+        }
+    }
+}
+
+/// First code points of the Unicode decimal digit runs accepted with
+/// [`InferOptions::unicode_digits`]
+const UNICODE_ZEROS: [u32; 9] = [
+    0x0660, // Arabic-Indic
+    0x06F0, // Extended Arabic-Indic (Persian, Urdu)
+    0x0966, // Devanagari
+    0x09E6, // Bengali
+    0x0BE6, // Tamil
+    0x0E50, // Thai
+    0x1040, // Myanmar
+    0x17E0, // Khmer
+    0xFF10, // Fullwidth
+];
+
+/// The ASCII digit `c` stands for: itself, or with `unicode_digits` the
+/// same digit in another script (`٢` becomes `2`)
+fn ascii_digit(c: char, options: &InferOptions) -> Option<char> {
+    if c.is_ascii_digit() {
+        return Some(c);
+    }
+    if !options.unicode_digits {
+        return None;
+    }
+    UNICODE_ZEROS
+        .iter()
+        .find_map(|&zero| (c as u32).checked_sub(zero).filter(|&d| d < 10))
+        .and_then(|d| char::from_digit(d, 10))
+}
+
+/// Push a separator token, extending the previous one when it is the same
+/// character directly before (`//`, `--`) so doubled separators don't add
+/// token positions
+fn push_separator(tokens: &mut Vec<Token>, c: char, position: usize) {
+    if let Some(last) = tokens.last_mut() {
+        let adjacent = last.position as usize + last.value.chars().count() == position;
+        if adjacent && last.possible_types.first() == Some(&TokenType::Separator(c)) {
+            last.value.push(c);
+            return;
+        }
+    }
+    tokens.push(Token::separator(c, position));
+}
+
+/// Strip matching brackets or quotes around the whole value, returning
+/// the inner text and its offset in characters
+fn strip_wrapping(input: &str) -> (&str, usize) {
+    const PAIRS: [(char, char); 6] = [('[', ']'), ('(', ')'), ('{', '}'), ('<', '>'), ('"', '"'), ('\'', '\'')];
+
+    let mut inner = input;
+    let mut offset = 0;
+    while let Some((open, close)) = PAIRS
+        .iter()
+        .copied()
+        .find(|&(open, close)| inner.len() >= 2 && inner.starts_with(open) && inner.ends_with(close))
+    {
+        inner = &inner[open.len_utf8()..inner.len() - close.len_utf8()];
+        offset += 1;
+    }
+    (inner, offset)
+}
+
+/// Length of a `±HH:MM` or `±HHMM` offset attached to `GMT` / `UTC`
+fn attached_offset_len(text: &str, mut rest: impl Iterator<Item = char>) -> Option<usize> {
+    if !(text.eq_ignore_ascii_case("gmt") || text.eq_ignore_ascii_case("utc")) {
+        return None;
+    }
+    if !matches!(rest.next(), Some('+' | '-')) {
+        return None;
+    }
+    let offset: String = rest.take_while(|c| c.is_ascii_digit() || *c == ':').collect();
+    let valid = match offset.as_bytes() {
+        [h1, h2, b':', m1, m2] | [h1, h2, m1, m2] => [h1, h2, m1, m2].iter().all(|b| b.is_ascii_digit()),
+        _ => false,
+    };
+    valid.then_some(offset.len() + 1)
+}
+
+/// Check if `rest` is an apostrophe followed by exactly two digits ('25)
+fn starts_abbreviated_year(mut rest: impl Iterator<Item = char>) -> bool {
+    rest.next();
+    let mut digits = 0;
+    for c in rest {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        digits += 1;
+    }
+    digits == 2
+}
+
+/// Check if an 8-digit run is a basic ISO date (YYYYMMDD)
+fn is_compact_date(digits: &str, options: &InferOptions) -> bool {
+    if digits.len() != 8 {
+        return false;
+    }
+    let part = |range: std::ops::Range<usize>| digits[range].parse::<u32>().unwrap_or(0);
+    options.year_range.contains(&part(0..4)) && (1..=12).contains(&part(4..6)) && (1..=31).contains(&part(6..8))
+}
+
+/// Check if a 4- or 6-digit run is a basic ISO time (HHMM or HHMMSS)
+fn is_compact_time(digits: &str) -> bool {
+    if digits.len() != 4 && digits.len() != 6 {
+        return false;
+    }
+    let part = |i: usize| digits[i..i + 2].parse::<u32>().unwrap_or(99);
+    part(0) <= 23 && part(2) <= 59 && (digits.len() == 4 || part(4) <= 59)
 }
 
 #[cfg(test)]
@@ -219,6 +456,46 @@ mod tests {
         assert!(tokens[2].possible_types.contains(&TokenType::MonthNameShort));
     }
 
+    #[test]
+    fn test_tokenize_month_abbreviation_period() {
+        let tokens = tokenize("15 Jan. 2025").unwrap();
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[2].value, "Jan.");
+        assert!(tokens[2].possible_types.contains(&TokenType::MonthNameShort));
+
+        // Only a period ending the word is absorbed
+        assert_eq!(tokenize("15.Jan.2025").unwrap()[2].value, "Jan");
+        assert_eq!(tokenize("Mon. 15").unwrap()[1].value, ".");
+    }
+
+    #[test]
+    fn test_tokenize_unicode_digits() {
+        let options = InferOptions {
+            unicode_digits: true,
+            ..Default::default()
+        };
+        let tokens = tokenize_with_options("٢٠٢٥-٠١-١٥", &options).unwrap();
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[0].value, "2025");
+        assert_eq!(tokens[4].numeric_value, Some(15));
+        assert_eq!(tokenize_with_options("१५/०३/२०२५", &options).unwrap()[4].value, "2025");
+
+        // Off by default
+        assert!(tokenize("٢٠٢٥-٠١-١٥").unwrap().iter().all(|t| t.numeric_value.is_none()));
+    }
+
+    #[test]
+    fn test_tokenize_collapses_doubled_separators() {
+        let tokens = tokenize("15//03//2025").unwrap();
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[1].value, "//");
+        assert_eq!(tokens[1].possible_types[0], TokenType::Separator('/'));
+        assert_eq!(tokens[2].position, 4);
+
+        // Different separators stay apart
+        assert_eq!(tokenize("15 / 03").unwrap().len(), 5);
+    }
+
     #[test]
     fn test_tokenize_with_time() {
         let tokens = tokenize("2025-01-15T10:30:00").unwrap();
@@ -227,9 +504,79 @@ mod tests {
         assert!(tokens.iter().any(|t| t.value == "T"));
     }
 
+    #[test]
+    fn test_tokenize_year_range() {
+        let tokens = tokenize("1776-07-04").unwrap();
+        assert!(!tokens[0].possible_types.contains(&TokenType::Year4));
+
+        let options = InferOptions {
+            year_range: 1700..=2100,
+            ..Default::default()
+        };
+        let tokens = tokenize_with_options("1776-07-04", &options).unwrap();
+        assert!(tokens[0].possible_types.contains(&TokenType::Year4));
+    }
+
     #[test]
     fn test_tokenize_timezone() {
         let tokens = tokenize("2025-01-15T10:30:00+05:30").unwrap();
         assert!(tokens.iter().any(|t| t.possible_types.contains(&TokenType::TzOffset)));
     }
+
+    #[test]
+    fn test_tokenize_tz_name_then_offset() {
+        let tokens = tokenize("2014-01-13 09:52:52 EST -0500").unwrap();
+        let last = tokens.last().unwrap();
+        assert_eq!(last.value, "-0500");
+        assert_eq!(last.possible_types.as_slice(), &[TokenType::TzOffset]);
+        assert_eq!(tokens[tokens.len() - 3].possible_types.as_slice(), &[TokenType::TzName]);
+    }
+
+    #[test]
+    fn test_tokenize_compact_iso() {
+        let tokens = tokenize("20250115T103000Z").unwrap();
+        let values: Vec<&str> = tokens.iter().map(|t| t.value.as_str()).collect();
+        assert_eq!(values, ["2025", "01", "15", "T", "10", "30", "00", "Z"]);
+        assert_eq!(tokens[4].possible_types.as_slice(), &[TokenType::Hour24]);
+        assert_eq!(tokens[6].position, 13);
+
+        // Not a plausible YYYYMMDD: left as a single token
+        assert_eq!(tokenize("12345678").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_tokenize_cjk_markers() {
+        let tokens = tokenize("2025年01月15日").unwrap();
+        let values: Vec<&str> = tokens.iter().map(|t| t.value.as_str()).collect();
+        assert_eq!(values, ["2025", "年", "01", "月", "15", "日"]);
+        assert_eq!(tokens[5].possible_types.as_slice(), &[TokenType::Separator('日')]);
+        assert_eq!(tokens[4].position, 8);
+    }
+
+    #[test]
+    fn test_tokenize_strips_wrapping() {
+        let tokens = tokenize("[2025-01-15 10:30:00]").unwrap();
+        assert_eq!(tokens.len(), 11);
+        assert_eq!(tokens[0].value, "2025");
+        assert_eq!(tokens[0].position, 1);
+
+        let tokens = tokenize("\"'15/03/2025'\"").unwrap();
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[4].value, "2025");
+
+        // Unbalanced: the stray bracket is skipped as before
+        assert_eq!(tokenize("[15/03/2025").unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_tokenize_gmt_attached_offset() {
+        let tokens = tokenize("10:30 GMT+05:30").unwrap();
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[4].value, "GMT+05:30");
+        assert_eq!(tokens[4].possible_types.as_slice(), &[TokenType::TzOffset]);
+
+        // Single-digit hours are not a strptime %z offset
+        let tokens = tokenize("10:30 UTC+2").unwrap();
+        assert_eq!(tokens[4].possible_types.as_slice(), &[TokenType::TzName]);
+    }
 }
@@ -0,0 +1,51 @@
+//! Integration tests for the `fastdateinfer` binary (`cli` feature).
+
+#![cfg(feature = "cli")]
+
+use assert_cmd::Command;
+
+fn cli() -> Command {
+    Command::cargo_bin("fastdateinfer").unwrap()
+}
+
+#[test]
+fn test_cli_reads_stdin() {
+    let output = cli().write_stdin("01/02/2025\n15/03/2025\n\n20/04/2025\n").output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout, "format: %d/%m/%Y\nconfidence: 1.000\n");
+}
+
+#[test]
+fn test_cli_monthfirst_and_explain() {
+    let output = cli()
+        .args(["--monthfirst", "--explain"])
+        .write_stdin("01/02/2025\n03/04/2025\n")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("format: %m/%d/%Y\n"));
+    assert!(stdout.contains("month"));
+    assert!(stdout.contains("day_or_month=2"));
+}
+
+#[test]
+fn test_cli_reads_file_argument() {
+    let path = std::env::temp_dir().join("fastdateinfer_cli_test.txt");
+    std::fs::write(&path, "2025-01-15\n2025-03-20\n").unwrap();
+    let output = cli().arg(&path).output().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout).unwrap().starts_with("format: %Y-%m-%d\n"));
+}
+
+#[test]
+fn test_cli_errors() {
+    let output = cli().arg("--strict").write_stdin("15/03/2025\n20/04/2025\nnot-a-date\n").output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8(output.stderr).unwrap().contains("strict validation failed"));
+
+    let output = cli().arg("--bogus").output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,77 @@
+//! End-to-end inference tests for formats that combine several subsystems
+//! (separators, time detection, subseconds, timezones) in a single string.
+
+use fastdateinfer::{infer, TokenType};
+
+#[test]
+fn test_nanosecond_iso_with_z() {
+    let dates = vec![
+        "2025-01-15T10:30:00.123456789Z",
+        "2025-03-20T14:45:30.654321987Z",
+    ];
+    let result = infer(&dates).unwrap();
+    assert_eq!(result.format, "%Y-%m-%dT%H:%M:%S.%fZ");
+    assert_eq!(result.token_types[12], TokenType::Subsecond);
+    assert_eq!(result.token_types[13], TokenType::TzZ);
+    assert!(result.confidence > 0.99);
+}
+
+#[test]
+fn test_mixed_subsecond_precision_with_z() {
+    let dates = vec![
+        "2025-01-15T10:30:00.123Z",
+        "2025-03-20T14:45:30.654321Z",
+        "2025-06-01T08:00:00.000000001Z",
+    ];
+    let result = infer(&dates).unwrap();
+    assert_eq!(result.format, "%Y-%m-%dT%H:%M:%S.%fZ");
+}
+
+#[test]
+fn test_basic_iso_compact_with_z() {
+    let dates = vec!["20250115T103000Z", "20250320T144530Z", "20250601T080000Z"];
+    let result = infer(&dates).unwrap();
+    assert_eq!(result.format, "%Y%m%dT%H%M%SZ");
+    assert_eq!(result.token_types[1], TokenType::Month);
+    assert_eq!(result.token_types[4], TokenType::Hour24);
+}
+
+#[test]
+fn test_basic_iso_compact_with_offset() {
+    let dates = vec!["20250115T103000+0100", "20250320T144530-0500"];
+    let result = infer(&dates).unwrap();
+    assert_eq!(result.format, "%Y%m%dT%H%M%S%z");
+}
+
+#[test]
+fn test_gmt_prefixed_colon_offset() {
+    let dates = vec!["2025-01-15 10:30:00 GMT+05:30", "2025-03-20 14:45:00 GMT-03:00"];
+    let result = infer(&dates).unwrap();
+    assert_eq!(result.format, "%Y-%m-%d %H:%M:%S GMT%z");
+    assert_eq!(result.token_types.last(), Some(&TokenType::TzOffset));
+}
+
+#[test]
+fn test_dash_separated_time_after_date() {
+    let dates = vec!["2025-01-15 10-30-00", "2025-03-20 14-45-30"];
+    let result = infer(&dates).unwrap();
+    assert_eq!(result.format, "%Y-%m-%d %H-%M-%S");
+    assert_eq!(result.token_types[6], TokenType::Hour24);
+
+    // A dashed triple without a preceding date stays a date
+    let result = infer(&["15-03-2025", "20-04-2025"]).unwrap();
+    assert_eq!(result.format, "%d-%m-%Y");
+}
+
+#[test]
+fn test_ordinal_date_with_time() {
+    let dates = vec!["2025-045T10:30:00", "2025-123T14:45:30", "2024-366T23:59:59"];
+    let result = infer(&dates).unwrap();
+    assert_eq!(result.format, "%Y-%jT%H:%M:%S");
+    assert_eq!(result.token_types[2], TokenType::DayOfYear);
+    assert_eq!(result.token_types[4], TokenType::Hour24);
+
+    let dates = vec!["2025-045T10:30:00.123Z", "2025-123T14:45:30.456Z"];
+    assert_eq!(infer(&dates).unwrap().format, "%Y-%jT%H:%M:%S.%fZ");
+    assert_eq!(infer(&["2025-045 10:30", "2025-300 11:15"]).unwrap().format, "%Y-%j %H:%M");
+}
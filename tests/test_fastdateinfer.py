@@ -91,6 +91,27 @@ class TestInfer:
         assert isinstance(result.token_types, list)
         assert result.confidence > 0.0
 
+    def test_token_type_tags(self):
+        result = fastdateinfer.infer(["15/03/2025", "20/04/2025"])
+        assert result.token_types == ["day", "sep:/", "month", "sep:/", "year4"]
+
+    def test_used_preference(self):
+        assert not fastdateinfer.infer(["15/03/2025", "01/02/2025"]).used_preference
+        assert fastdateinfer.infer(["01/02/2025", "03/04/2025"]).used_preference
+
+    def test_components(self):
+        components = fastdateinfer.infer(["15/03/2025", "20/04/2025"]).components
+        assert components[0] == {"type": "day", "specifier": "%d", "position": 0}
+        assert components[1] == {"type": "sep:/", "specifier": "/", "position": 1}
+        assert "".join(c["specifier"] for c in components) == "%d/%m/%Y"
+
+    def test_to_regex(self):
+        import re
+
+        pattern = re.compile(fastdateinfer.infer(["15 Mar 2025", "20 Apr 2025"]).to_regex())
+        assert pattern.match("5 may 2024").groups() == ("5", "may", "2024")
+        assert not pattern.match("15/03/2025")
+
     def test_repr(self):
         result = fastdateinfer.infer(["15/03/2025", "20/04/2025"])
         r = repr(result)
@@ -111,6 +132,18 @@ class TestInfer:
                 ["01/02/2025", "03/04/2025"], min_confidence=1.1
             )
 
+    def test_exception_classes(self):
+        with pytest.raises(fastdateinfer.EmptyInputError):
+            fastdateinfer.infer([])
+        with pytest.raises(fastdateinfer.LowConfidenceError):
+            fastdateinfer.infer(["01/02/2025"], min_confidence=1.1)
+        with pytest.raises(fastdateinfer.InconsistentFormatsError):
+            fastdateinfer.infer(["15/03/2025", "2025-01-15T10:30:00", "Jan"])
+        with pytest.raises(fastdateinfer.StrictValidationError):
+            fastdateinfer.infer(["15/03/2025", "20/04/2025", "bad"], strict=True)
+        assert issubclass(fastdateinfer.StrictValidationError, fastdateinfer.InferenceError)
+        assert issubclass(fastdateinfer.InferenceError, ValueError)
+
 
 # =========================================
 # TestInferFormat
@@ -246,6 +279,31 @@ class TestInconsistentFormatsTolerance:
         assert result.confidence > 0.0
 
 
+# =========================================
+# TestSkipNulls
+# =========================================
+
+
+class TestSkipNulls:
+    """Tests for the skip_nulls option."""
+
+    def test_skipped_count(self):
+        result = fastdateinfer.infer(
+            ["15/03/2025", "", "N/A", "20/04/2025", "null", "NaN"],
+            skip_nulls=True,
+        )
+        assert result.format == "%d/%m/%Y"
+        assert result.skipped_count == 4
+        assert result.confidence == 1.0
+
+    def test_default_does_not_skip(self):
+        result = fastdateinfer.infer(
+            ["15/03/2025", "20/04/2025", "25/12/2025", "N/A"]
+        )
+        assert result.skipped_count == 0
+        assert result.confidence < 1.0
+
+
 # =========================================
 # TestPrescanSamplingFix
 # =========================================